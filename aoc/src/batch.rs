@@ -0,0 +1,201 @@
+//! Solve a day for many inputs at once, e.g. its examples or everyone's real inputs
+
+use crate::days;
+use anyhow::{anyhow, bail, Context, Result};
+use common::config::Config;
+use common::{input, Part, Puzzle};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// What solving one input gave
+struct Row {
+    file: PathBuf,
+    answers: [Result<String>; 2],
+    /// Parsing and solving both parts
+    time: Duration,
+}
+
+/// The files `patterns` name: every file in a directory, the matches of a glob or the file itself
+fn inputs(patterns: &[String]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for pattern in patterns {
+        let path = Path::new(pattern);
+        let mut matches = if path.is_dir() {
+            let mut entries = Vec::new();
+            for entry in
+                fs::read_dir(path).with_context(|| format!("unable to read {}", path.display()))?
+            {
+                entries.push(entry?.path());
+            }
+            entries
+        } else {
+            glob::glob(pattern)
+                .with_context(|| format!("invalid pattern '{pattern}'"))?
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        matches.retain(|p| p.is_file());
+        if matches.is_empty() {
+            bail!("no inputs in {pattern}");
+        }
+        matches.sort();
+        files.extend(matches);
+    }
+    Ok(files)
+}
+
+/// Solve both parts of `file`. A file that can't be read fails both.
+fn solve(puzzle: &dyn Puzzle, file: &Path) -> Row {
+    let start = Instant::now();
+    let answers = match input::read(file) {
+        Ok(input) => Part::ALL.map(|part| puzzle.solve(&input, part)),
+        Err(e) => {
+            let e = format!("{e:#}");
+            Part::ALL.map(|_| Err(anyhow!("{e}")))
+        }
+    };
+    Row {
+        file: file.to_path_buf(),
+        answers,
+        time: start.elapsed(),
+    }
+}
+
+/// The rows as aligned columns under a header, followed by the errors behind any failed part
+fn table(rows: &[Row]) -> Vec<String> {
+    let cells: Vec<[String; 4]> = rows
+        .iter()
+        .map(|row| {
+            let [part1, part2] = row.answers.each_ref().map(|a| match a {
+                Ok(answer) => answer.clone(),
+                Err(_) => "error".to_string(),
+            });
+            [
+                row.file.display().to_string(),
+                part1,
+                part2,
+                format!("{:.1?}", row.time),
+            ]
+        })
+        .collect();
+    let header = ["file", "part 1", "part 2", "time"].map(String::from);
+    let widths: Vec<usize> = (0..4)
+        .map(|i| {
+            cells
+                .iter()
+                .chain([&header])
+                .map(|c| c[i].len())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut lines: Vec<String> = [&header]
+        .into_iter()
+        .chain(&cells)
+        .map(|c| {
+            format!(
+                "{:w0$}  {:w1$}  {:w2$}  {:>w3$}",
+                c[0],
+                c[1],
+                c[2],
+                c[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3],
+            )
+        })
+        .collect();
+    for row in rows {
+        for (part, answer) in Part::ALL.into_iter().zip(&row.answers) {
+            if let Err(e) = answer {
+                lines.push(format!("{} part {part}: {e:#}", row.file.display()));
+            }
+        }
+    }
+    lines
+}
+
+/// Solve both parts of `day` for every input `patterns` name and print them as a table, failing
+/// if any part of any input failed
+pub fn batch(day: u8, patterns: &[String], config: &Config) -> Result<()> {
+    let puzzle = days::find_configured(day, config)?;
+    let rows = inputs(patterns)?
+        .iter()
+        .map(|file| solve(puzzle.as_ref(), file))
+        .collect::<Vec<_>>();
+    for line in table(&rows) {
+        println!("{line}");
+    }
+
+    let failed = rows
+        .iter()
+        .filter(|r| r.answers.iter().any(Result::is_err))
+        .count();
+    if failed > 0 {
+        bail!("{failed} of {} inputs failed", rows.len());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inputs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = |name: &str| dir.path().join(name);
+        fs::create_dir(path("alice")).unwrap();
+        for name in ["test2.txt", "test1.txt", "notes.md", "alice/input.txt"] {
+            fs::write(path(name), "").unwrap();
+        }
+        let pattern = |name: &str| path(name).display().to_string();
+
+        assert_eq!(
+            inputs(&[pattern("test*.txt")]).unwrap(),
+            [path("test1.txt"), path("test2.txt")]
+        );
+        // directories are skipped, unless named themselves
+        assert_eq!(
+            inputs(&[pattern(""), pattern("alice")]).unwrap(),
+            [
+                path("notes.md"),
+                path("test1.txt"),
+                path("test2.txt"),
+                path("alice/input.txt")
+            ]
+        );
+        assert!(inputs(&[pattern("*.toml")]).is_err());
+    }
+
+    #[test]
+    fn test_table() {
+        let puzzle = days::find(1).unwrap();
+        let dir = common::workspace::root().join("day1");
+        let rows = [
+            solve(puzzle.as_ref(), &dir.join("test1.txt")),
+            solve(puzzle.as_ref(), &dir.join("test2.txt")),
+            solve(puzzle.as_ref(), &dir.join("missing.txt")),
+        ];
+        let lines = table(&rows);
+
+        fn columns(line: &str) -> Vec<&str> {
+            line.split_whitespace().skip(1).take(2).collect()
+        }
+        assert!(lines[0].contains("file") && lines[0].ends_with("time"));
+        assert_eq!(columns(&lines[1]), ["142", "142"]);
+        assert_eq!(columns(&lines[2]), ["error", "281"]);
+        assert_eq!(columns(&lines[3]), ["error", "error"]);
+        assert_eq!(lines.len(), 7);
+        assert!(lines[4].contains("test2.txt part 1: "), "{}", lines[4]);
+        assert!(
+            lines[6].contains("missing.txt part 2: unable to read"),
+            "{}",
+            lines[6]
+        );
+        // columns line up
+        assert_eq!(lines[1].find("142"), lines[2].find("error"));
+    }
+}
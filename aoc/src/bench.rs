@@ -0,0 +1,58 @@
+//! Benchmark parsing and solving of one or every day
+
+use crate::days;
+use anyhow::Result;
+use common::bench::{Report, Stats};
+use common::config::Config;
+use common::Puzzle;
+use common::{input, Part};
+
+fn print_row(label: &str, stats: &Stats) {
+    println!(
+        "  {label:<16} mean {:>10.1?}  min {:>10.1?}  stddev {:>10.1?}",
+        stats.mean, stats.min, stats.stddev
+    );
+}
+
+fn print_report(report: &Report, iterations: usize) {
+    println!("Day {} ({iterations} iterations)", report.day);
+    print_row("parse", &report.parse);
+
+    for part in &report.parts {
+        let label = match part.name {
+            None => format!("part {}", part.part),
+            Some(name) => format!("part {} ({name})", part.part),
+        };
+        print_row(&label, &part.stats);
+
+        // alternatives should always agree with the implementation that's actually used
+        let main = report
+            .parts
+            .iter()
+            .find(|p| p.part == part.part && p.name.is_none());
+        if let Some(main) = main {
+            if main.answer != part.answer {
+                println!(
+                    "    answer differs: {} vs {} from part {}",
+                    part.answer, main.answer, part.part
+                );
+            }
+        }
+    }
+}
+
+/// Benchmark `day` (or every day) against its input, with the inputs and parameters `config` sets
+pub fn bench(day: Option<u8>, parts: &[Part], iterations: usize, config: &Config) -> Result<()> {
+    let puzzles: Vec<Box<dyn Puzzle>> = match day {
+        Some(day) => vec![days::find_configured(day, config)?],
+        None => days::configured(config)?,
+    };
+
+    for puzzle in puzzles {
+        let input = input::read(&config.input(puzzle.day()))?;
+        let report = puzzle.bench(&input, parts, iterations)?;
+        print_report(&report, iterations);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,237 @@
+use anyhow::{anyhow, Result};
+use common::config::Config;
+use common::Puzzle;
+
+/// Every day the runner knows how to solve, in order
+pub fn all() -> Vec<Box<dyn Puzzle>> {
+    vec![
+        Box::new(day1::Day1),
+        Box::new(day2::Day2::default()),
+        Box::new(day3::Day3),
+        Box::new(day4::Day4),
+        Box::new(day5::Day5),
+        Box::new(day6::Day6),
+        Box::new(day7::Day7::default()),
+        Box::new(day8::Day8::default()),
+        Box::new(day9::Day9),
+        Box::new(day10::Day10),
+        Box::new(day11::Day11::default()),
+    ]
+}
+
+/// Look up a single day
+pub fn find(day: u8) -> Option<Box<dyn Puzzle>> {
+    all().into_iter().find(|p| p.day() == day)
+}
+
+/// Every day, with the parameters `config` sets
+pub fn configured(config: &Config) -> Result<Vec<Box<dyn Puzzle>>> {
+    let mut puzzles = all();
+    for puzzle in &mut puzzles {
+        config.configure(puzzle.as_mut())?;
+    }
+    Ok(puzzles)
+}
+
+/// Look up a single day, with the parameters `config` sets
+pub fn find_configured(day: u8, config: &Config) -> Result<Box<dyn Puzzle>> {
+    let mut puzzle = find(day).ok_or_else(|| anyhow!("day {day} is not solved yet"))?;
+    config.configure(puzzle.as_mut())?;
+    Ok(puzzle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Part;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::path::Path;
+
+    /// Input that isn't valid for any day. Solving it can succeed or fail, but must not panic.
+    const GARBAGE: &[&str] = &[
+        "",
+        "\n",
+        "\n\n\n",
+        "x",
+        "1 2 3\n\n4",
+        "-1",
+        "Card 1: | \n",
+        "seeds:\n\n",
+        "Time:\nDistance:\n",
+        "AAA = (BBB, CCC)\n",
+        "S",
+        "#",
+        "🎄🎄\n🎄",
+    ];
+
+    /// Odd number of seeds, so there are no ranges for part 2
+    const ODD_SEEDS: &str = "seeds: 79 14 55
+
+seed-to-soil map:
+50 98 2
+
+soil-to-fertilizer map:
+0 15 37
+
+fertilizer-to-water map:
+49 53 8
+
+water-to-light map:
+88 18 7
+
+light-to-temperature map:
+45 77 23
+
+temperature-to-humidity map:
+0 69 1
+
+humidity-to-location map:
+60 56 37
+";
+
+    /// Input malformed for one day that must be reported as an error for the given parts
+    const MALFORMED: &[(u8, &[Part], &str)] = &[
+        (1, &Part::ALL, "1abc2\nabc\n"),
+        (2, &Part::ALL, "Game 1: 3 blue\nGame 2 3 blue\n"),
+        (2, &Part::ALL, "Game 1: 3 purple\n"),
+        (3, &Part::ALL, "467..\n...*\n"),
+        (4, &Part::ALL, "Card 1 41 48 | 83 86\n"),
+        (4, &Part::ALL, "Card 1: 41 48 83 86\n"),
+        (
+            5,
+            &Part::ALL,
+            "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n",
+        ),
+        (5, &[Part::Two], ODD_SEEDS),
+        (6, &Part::ALL, "Time: 7 15\nDistance: 9\n"),
+        (7, &Part::ALL, "32T3K\n"),
+        (7, &Part::ALL, "32T3X 765\n"),
+        (7, &Part::ALL, "32T3KK 765\n"),
+        (8, &Part::ALL, "\nAAA = (BBB, BBB)\n"),
+        (8, &Part::ALL, "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\n"),
+        (8, &Part::ALL, "LR\n\nAAA = (BBB, CCC)\n"),
+        (9, &Part::ALL, "0 3 6\n1 x 3\n"),
+        (10, &Part::ALL, ".....\n.F-7.\n.|.|.\n.L-J.\n.....\n"),
+        (10, &Part::ALL, "S....\n.....\n"),
+        (10, &Part::ALL, "S-7\n|.|\nL-\n"),
+        (10, &Part::ALL, "S-7\n|.|\nL-.\n"),
+        (11, &Part::ALL, "#..\n.#\n"),
+    ];
+
+    fn solve(puzzle: &dyn Puzzle, input: &str, part: Part) -> anyhow::Result<String> {
+        catch_unwind(AssertUnwindSafe(|| puzzle.solve(input, part)))
+            .unwrap_or_else(|_| panic!("day {} part {part} panicked on {input:?}", puzzle.day()))
+    }
+
+    #[test]
+    fn test_garbage_does_not_panic() {
+        for puzzle in all() {
+            for input in GARBAGE {
+                for part in Part::ALL {
+                    let _ = solve(puzzle.as_ref(), input, part);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for puzzle in all() {
+            for size in [1, 2, 10, 25] {
+                for seed in 0..5 {
+                    let Some(input) = puzzle.generate(seed, size) else {
+                        continue;
+                    };
+                    for part in Part::ALL {
+                        if let Err(e) = solve(puzzle.as_ref(), &input, part) {
+                            panic!(
+                                "day {} part {part} failed on generated input ({e}):\n{input}",
+                                puzzle.day()
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_malformed_is_an_error() {
+        for (day, parts, input) in MALFORMED {
+            let puzzle = find(*day).unwrap();
+            for part in *parts {
+                assert!(
+                    solve(puzzle.as_ref(), input, *part).is_err(),
+                    "day {day} part {part} accepted {input:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_configured() {
+        let config = Config::parse(
+            r#"
+[day2.params]
+bag = { red = 20, green = 20, blue = 20 }
+
+[day7.params]
+card_order = "AKQJT98765432"
+
+[day8.params]
+start = "A"
+end = "B"
+
+[day11.params]
+part1_expansion = 10
+part2_expansion = 100
+"#,
+            Path::new(""),
+        )
+        .unwrap();
+        let solve = |day, input, part| {
+            find_configured(day, &config)
+                .unwrap()
+                .solve(input, part)
+                .unwrap()
+        };
+
+        assert_eq!(
+            solve(2, include_str!("../../day2/test1.txt"), Part::One),
+            "15"
+        );
+        assert_eq!(
+            solve(7, include_str!("../../day7/test.txt"), Part::One),
+            "6833"
+        );
+        assert_eq!(
+            solve(8, include_str!("../../day8/test3.txt"), Part::Two),
+            "1"
+        );
+        let galaxies = include_str!("../../day11/test1.txt");
+        assert_eq!(solve(11, galaxies, Part::One), "1030");
+        assert_eq!(solve(11, galaxies, Part::Two), "8410");
+
+        // what the parameters don't touch is as it was
+        assert_eq!(
+            solve(2, include_str!("../../day2/test1.txt"), Part::Two),
+            "2286"
+        );
+        assert_eq!(configured(&config).unwrap().len(), all().len());
+    }
+
+    #[test]
+    fn test_configured_errors() {
+        for raw in [
+            "[day1.params]\nmax = 3\n",
+            "[day2.params]\nbag = { red = 20 }\n",
+            "[day7.params]\ncard_order = \"AKQJT9876543\"\n",
+            "[day7.params]\njoker_order = \"AKQJT98765432J\"\n",
+            "[day8.params]\nstart = \"AA\"\n",
+            "[day11.params]\npart2_expansion = 0\n",
+        ] {
+            let config = Config::parse(raw, Path::new("")).unwrap();
+            assert!(configured(&config).is_err(), "{raw}");
+        }
+    }
+}
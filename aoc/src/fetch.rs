@@ -0,0 +1,143 @@
+//! Download puzzle inputs from the Advent of Code site, caching every one so a day is only ever
+//! downloaded once
+
+use crate::site::Site;
+use anyhow::{bail, Context, Result};
+use common::workspace;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where an input came from
+#[derive(Debug, PartialEq, Eq)]
+pub enum Source {
+    Cache,
+    Download,
+}
+
+/// Downloads inputs from the site into `cache_dir`
+pub struct Fetcher {
+    pub site: Site,
+    pub cache_dir: PathBuf,
+}
+
+impl Fetcher {
+    /// The cached copy of `day`'s input
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{day}.txt"))
+    }
+
+    /// The input of `day`, from the cache if it's there and downloaded into it otherwise
+    pub fn input(&self, day: u8) -> Result<(String, Source)> {
+        let cached = self.cache_path(day);
+        if cached.exists() {
+            let input = fs::read_to_string(&cached)
+                .with_context(|| format!("unable to read {}", cached.display()))?;
+            return Ok((input, Source::Cache));
+        }
+
+        let input = self.site.get(&format!("day/{day}/input"))?;
+        fs::create_dir_all(&self.cache_dir)
+            .with_context(|| format!("unable to create {}", self.cache_dir.display()))?;
+        fs::write(&cached, &input)
+            .with_context(|| format!("unable to write {}", cached.display()))?;
+        Ok((input, Source::Download))
+    }
+
+    /// Get `day`'s input and write it to `dest`. An existing `dest` with different contents is
+    /// left alone and reported as an error.
+    pub fn fetch(&self, day: u8, dest: &Path) -> Result<Source> {
+        let (input, source) = self.input(day)?;
+        match fs::read_to_string(dest) {
+            Ok(existing) if existing == input => {}
+            Ok(_) => bail!(
+                "{} already exists and differs from the fetched input in {}",
+                dest.display(),
+                self.cache_path(day).display()
+            ),
+            Err(_) => fs::write(dest, &input)
+                .with_context(|| format!("unable to write {}", dest.display()))?,
+        }
+        Ok(source)
+    }
+}
+
+/// Inputs are cached at the root of the workspace unless told otherwise
+pub fn default_cache_dir() -> PathBuf {
+    workspace::root().join(".cache").join("inputs")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::stand_in::{serve, SESSION};
+    use std::sync::atomic::Ordering;
+
+    /// Every input request answered with `status` and `body`
+    fn always(status: u16, body: &'static str) -> impl Fn(&str, &str, &str) -> (u16, String) {
+        move |method, url, _| {
+            assert_eq!(method, "GET");
+            assert!(url.ends_with("/input"), "{url}");
+            (status, body.to_string())
+        }
+    }
+
+    #[test]
+    fn test_fetch_downloads_once() {
+        let (site, requests) = serve(SESSION, always(200, "1abc2\n"));
+        let dir = tempfile::tempdir().unwrap();
+        let fetcher = Fetcher {
+            site,
+            cache_dir: dir.path().join("cache"),
+        };
+        let dest = dir.path().join("input.txt");
+
+        assert_eq!(fetcher.fetch(1, &dest).unwrap(), Source::Download);
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "1abc2\n");
+        assert_eq!(
+            fs::read_to_string(fetcher.cache_path(1)).unwrap(),
+            "1abc2\n"
+        );
+
+        fs::remove_file(&dest).unwrap();
+        assert_eq!(fetcher.fetch(1, &dest).unwrap(), Source::Cache);
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "1abc2\n");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("input.txt");
+        let page = "Please don't repeatedly request this endpoint";
+
+        for (session, status) in [(SESSION, "404"), ("expired", "400")] {
+            let (site, requests) = serve(session, always(404, page));
+            let fetcher = Fetcher {
+                site,
+                cache_dir: dir.path().to_path_buf(),
+            };
+            let e = fetcher.fetch(25, &dest).unwrap_err().to_string();
+            assert!(e.contains(&format!("returned {status}")), "{e}");
+            assert_eq!(requests.load(Ordering::SeqCst), 1);
+        }
+
+        // nothing is cached or written for a failed download
+        assert!(!dir.path().join("day25.txt").exists());
+        assert!(!dest.exists());
+    }
+
+    #[test]
+    fn test_fetch_keeps_existing_input() {
+        let (site, _) = serve(SESSION, always(200, "new\n"));
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("input.txt");
+        fs::write(&dest, "old\n").unwrap();
+
+        let fetcher = Fetcher {
+            site,
+            cache_dir: dir.path().to_path_buf(),
+        };
+        assert!(fetcher.fetch(3, &dest).is_err());
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "old\n");
+    }
+}
@@ -0,0 +1,285 @@
+use anyhow::{anyhow, bail, Result};
+use clap::{Parser, Subcommand};
+use common::cli::{self, ConfigArgs, RunArgs, Verbosity};
+use common::config::{self, Config};
+use common::output::Solved;
+use common::{answers, history};
+use common::{input, workspace, Part};
+use rayon::prelude::*;
+use site::SiteArgs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+mod batch;
+mod bench;
+mod days;
+mod fetch;
+mod scaffold;
+mod site;
+mod submit;
+mod verify;
+mod watch;
+
+/// Advent of Code 2023 runner
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    config: ConfigArgs,
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day, or every day at once
+    Run {
+        /// Day to solve [default: every day, concurrently]
+        day: Option<u8>,
+        #[command(flatten)]
+        args: RunArgs,
+    },
+    /// Solve a day for every input in directories or matching globs and print a table of answers
+    Batch {
+        /// Day to solve
+        day: u8,
+        /// Directories to solve every file in, globs such as `day10/test*.txt`, or files
+        #[arg(required = true, value_name = "PATH")]
+        inputs: Vec<String>,
+    },
+    /// Time parsing and solving of a day's input.txt, including alternative implementations
+    Bench {
+        /// Day to benchmark [default: every day]
+        day: Option<u8>,
+        /// Only benchmark this part (1 or 2)
+        #[arg(short, long)]
+        part: Option<Part>,
+        /// Number of timed runs of each step
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+    },
+    /// Solve every day and check the answers against the answers manifest
+    Verify {
+        /// Answers manifest [default: answers.toml at the workspace root]
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+        /// Add answers missing from the manifest instead of only reporting them
+        #[arg(long)]
+        record: bool,
+    },
+    /// Download a day's input into its input.txt, unless it's already cached
+    Fetch {
+        /// Day to fetch
+        day: u8,
+        #[command(flatten)]
+        site: SiteArgs,
+        /// Where downloaded inputs are kept [default: .cache/inputs at the workspace root]
+        #[arg(long, env = "AOC_CACHE_DIR", value_name = "PATH")]
+        cache_dir: Option<PathBuf>,
+    },
+    /// Solve a part of a day and submit the answer, unless it's already known to be wrong
+    Submit {
+        /// Day to submit
+        day: u8,
+        /// Part to submit (1 or 2)
+        part: Part,
+        #[command(flatten)]
+        site: SiteArgs,
+        /// Every answer submitted so far [default: .cache/submissions.toml at the workspace root]
+        #[arg(long, value_name = "PATH")]
+        history: Option<PathBuf>,
+        /// Answers manifest correct answers are added to [default: answers.toml at the
+        /// workspace root]
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+    },
+    /// Start a new day: a crate with a Solution skeleton, registered with the workspace and runner
+    New {
+        /// Day to start
+        day: u8,
+        /// Title of the puzzle, for the doc comment
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Print a random, valid input for a day
+    Generate {
+        /// Day to generate input for
+        day: u8,
+        /// How big the input is: lines, cards or games for the list shaped days, width and
+        /// height for the grids, and ranges per map for day 5
+        #[arg(short, long, default_value_t = 100)]
+        size: usize,
+        /// Seed for the generator, so the same input can be made again [default: random]
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Load a day's input once and query it interactively, with commands the day offers
+    Repl {
+        /// Day to load
+        day: u8,
+        /// Puzzle input to load [default: the day's input in the config, or its input.txt]
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<PathBuf>,
+    },
+    /// Rerun a day against its examples and input whenever its sources or inputs change
+    Watch {
+        /// Day to watch
+        day: u8,
+    },
+}
+
+fn run(day: u8, args: &RunArgs, config: &Config) -> Result<()> {
+    let puzzle = days::find_configured(day, config)?;
+    cli::solve(puzzle.as_ref(), args, config)
+}
+
+/// Solve every day against its input on the thread pool, then print the answers in order
+fn run_all(args: &RunArgs, config: &Config) -> Result<()> {
+    if args.input.is_some() {
+        bail!("--input needs a day to solve");
+    }
+
+    let puzzles = days::configured(config)?;
+    let solved: Vec<Result<Vec<Solved>>> = puzzles
+        .par_iter()
+        .map(|puzzle| {
+            let input = args.read_input(puzzle.day(), config)?;
+            args.parts()
+                .into_iter()
+                .map(|part| puzzle.solve_timed(&input, part))
+                .collect()
+        })
+        .collect();
+
+    let mut failed = 0;
+    for (puzzle, answers) in puzzles.iter().zip(solved) {
+        let mut ok = true;
+        match answers {
+            Ok(answers) => {
+                for solved in answers {
+                    println!("{}", solved.line(args.format)?);
+                    if let Err(e) = args.check(puzzle.day(), solved.part, &solved.answer, config) {
+                        ok = false;
+                        eprintln!("{e}");
+                    }
+                }
+            }
+            Err(e) => {
+                ok = false;
+                eprintln!("Day {}: {e:#}", puzzle.day());
+            }
+        }
+        if !ok {
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        bail!("{failed} days failed");
+    }
+    Ok(())
+}
+
+fn fetch(day: u8, fetcher: &fetch::Fetcher) -> Result<()> {
+    let dest = input::default_path(day);
+    let source = fetcher.fetch(day, &dest)?;
+    let from = match source {
+        fetch::Source::Cache => "cache",
+        fetch::Source::Download => "download",
+    };
+    println!("Day {day} input ({from}) is in {}", dest.display());
+    Ok(())
+}
+
+fn generate(day: u8, size: usize, seed: Option<u64>) -> Result<()> {
+    let puzzle = days::find(day).ok_or_else(|| anyhow!("day {day} is not solved yet"))?;
+    let seed = seed.unwrap_or_else(|| {
+        let seed = rand::random();
+        eprintln!("seed: {seed}");
+        seed
+    });
+    let input = puzzle
+        .generate(seed, size)
+        .ok_or_else(|| anyhow!("day {day} has no input generator"))?;
+    print!("{input}");
+    Ok(())
+}
+
+fn repl(day: u8, path: &Path, config: &Config) -> Result<()> {
+    if path == Path::new("-") {
+        bail!("stdin is for the commands, give the input as a file");
+    }
+    let puzzle = days::find_configured(day, config)?;
+    let input = input::read(path)?;
+    puzzle.repl(&input, &mut io::stdin().lock(), &mut io::stdout())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    cli.verbosity.init();
+
+    match cli.command {
+        Command::Run {
+            day: Some(day),
+            args,
+        } => run(day, &args, &cli.config.load()?),
+        Command::Run { day: None, args } => run_all(&args, &cli.config.load()?),
+        Command::Batch { day, inputs } => batch::batch(day, &inputs, &cli.config.load()?),
+        Command::Bench {
+            day,
+            part,
+            iterations,
+        } => {
+            let parts = part.map_or(Part::ALL.to_vec(), |p| vec![p]);
+            bench::bench(day, &parts, iterations, &cli.config.load()?)
+        }
+        Command::Verify { answers, record } => {
+            let manifest = answers.unwrap_or_else(answers::default_path);
+            verify::verify(&manifest, record)
+        }
+        Command::Fetch {
+            day,
+            site,
+            cache_dir,
+        } => fetch(
+            day,
+            &fetch::Fetcher {
+                site: site.site(),
+                cache_dir: cache_dir.unwrap_or_else(fetch::default_cache_dir),
+            },
+        ),
+        Command::Submit {
+            day,
+            part,
+            site,
+            history,
+            answers,
+        } => submit::submit(
+            day,
+            part,
+            &submit::Submitter {
+                site: site.site(),
+                history: history.unwrap_or_else(history::default_path),
+            },
+            &answers.unwrap_or_else(answers::default_path),
+        ),
+        Command::New { day, title } => {
+            scaffold::new_day(&workspace::root(), day, title.as_deref())?;
+            println!(
+                "Created day{day}; add its example to day{day}/test.txt and run `aoc fetch {day}`"
+            );
+            Ok(())
+        }
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+        Command::Repl { day, input } => {
+            let config = cli.config.load()?;
+            repl(day, &input.unwrap_or_else(|| config.input(day)), &config)
+        }
+        Command::Watch { day } => {
+            let config = cli.config.config.unwrap_or_else(config::default_path);
+            watch::watch(day, &config)
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Start a new day: a crate in the workspace with a `Solution` skeleton, registered with the runner
+
+use anyhow::{bail, Context, Result};
+use common::workspace::day_key;
+use std::fs;
+use std::path::Path;
+
+fn cargo_toml(day: u8) -> String {
+    format!(
+        r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+anyhow = "1.0.79"
+common = {{ path = "../common" }}
+nom = "7.1.3"
+
+[features]
+# check arithmetic on puzzle numbers for overflow, see common/src/arith.rs
+checked = ["common/checked"]
+"#
+    )
+}
+
+fn main_rs(day: u8) -> String {
+    format!(
+        r#"fn main() -> anyhow::Result<()> {{
+    common::cli::run_main(&mut day{day}::Day{day})
+}}
+"#
+    )
+}
+
+fn lib_rs(day: u8, title: Option<&str>) -> String {
+    let doc = match title {
+        Some(title) => format!("Day {day}: {title}"),
+        None => format!("Day {day}"),
+    };
+    format!(
+        r#"use anyhow::Result;
+use common::Solution;
+
+/// {doc}
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {{
+        Ok(input.lines().map(String::from).collect())
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use common::assert_examples;
+
+    #[test]
+    fn test_examples() {{
+        // add each example as its part is unlocked, e.g.
+        // (include_str!("../test.txt"), common::Part::One, "142"),
+        assert_examples(&Day{day}, &[]);
+    }}
+}}
+"#
+    )
+}
+
+/// Insert `line` into `text` in day order, among the lines `day_of` finds a day in
+fn insert_line(
+    text: &str,
+    line: &str,
+    day: u8,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, day_of(l)?)))
+        .collect();
+    if days.iter().any(|&(_, d)| d == day) {
+        bail!("day {day} is already there");
+    }
+    let at = match days.iter().rev().find(|&&(_, d)| d < day) {
+        Some(&(i, _)) => i + 1,
+        None => match days.first() {
+            Some(&(i, _)) => i,
+            None => bail!("no days to add day {day} next to"),
+        },
+    };
+
+    let mut out: Vec<&str> = lines[..at].to_vec();
+    out.push(line);
+    out.extend_from_slice(&lines[at..]);
+    Ok(out.join("\n") + "\n")
+}
+
+/// The day in `line` between `prefix` and `suffix` (after leading whitespace)
+fn day_between(line: &str, prefix: &str, suffix: &str) -> Option<u8> {
+    line.trim_start()
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+/// `path` with `edit` applied
+fn edited(path: &Path, edit: impl FnOnce(&str) -> Result<String>) -> Result<String> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("unable to read {}", path.display()))?;
+    edit(&text).with_context(|| format!("unable to update {}", path.display()))
+}
+
+/// Create the `day` crate in the workspace at `root` and register it with the workspace and the
+/// runner
+pub fn new_day(root: &Path, day: u8, title: Option<&str>) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("there is no day {day}, days go from 1 to 25");
+    }
+    let dir = root.join(day_key(day));
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    // work out every registration before writing any, so nothing changes if one can't be made
+    let members = root.join("Cargo.toml");
+    let deps = root.join("aoc").join("Cargo.toml");
+    let days = root.join("aoc").join("src").join("days.rs");
+    let registrations = [
+        (
+            &members,
+            edited(&members, |t| {
+                insert_line(t, &format!("    \"day{day}\","), day, |l| {
+                    day_between(l, "\"day", "\",")
+                })
+            })?,
+        ),
+        (
+            &deps,
+            edited(&deps, |t| {
+                let t = insert_line(
+                    t,
+                    &format!("day{day} = {{ path = \"../day{day}\" }}"),
+                    day,
+                    |l| day_between(l.split(' ').next()?, "day", ""),
+                )?;
+                insert_line(&t, &format!("    \"day{day}/checked\","), day, |l| {
+                    day_between(l, "\"day", "/checked\",")
+                })
+            })?,
+        ),
+        (
+            &days,
+            edited(&days, |t| {
+                insert_line(
+                    t,
+                    &format!("        Box::new(day{day}::Day{day}),"),
+                    day,
+                    |l| day_between(l.split("::Day").next()?, "Box::new(day", ""),
+                )
+            })?,
+        ),
+    ];
+    for (path, text) in registrations {
+        fs::write(path, text).with_context(|| format!("unable to write {}", path.display()))?;
+    }
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), cargo_toml(day))?;
+    fs::write(dir.join("src").join("main.rs"), main_rs(day))?;
+    fs::write(dir.join("src").join("lib.rs"), lib_rs(day, title))?;
+    fs::write(dir.join("test.txt"), "")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMBERS: &str = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n    \"day2\",\n    \"day10\",\n    \"grid\",\n]\n";
+    const DEPS: &str = "[dependencies]\ncommon = { path = \"../common\" }\nday1 = { path = \"../day1\" }\nday2 = { path = \"../day2\" }\nday10 = { path = \"../day10\" }\nrand = \"0.8.5\"\n\n[features]\nchecked = [\n    \"common/checked\",\n    \"day1/checked\",\n    \"day2/checked\",\n    \"day10/checked\",\n]\n";
+    const DAYS: &str = "pub fn all() -> Vec<Box<dyn Puzzle>> {\n    vec![\n        Box::new(day1::Day1),\n        Box::new(day2::Day2),\n        Box::new(day10::Day10),\n    ]\n}\n";
+
+    fn workspace() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("aoc").join("src")).unwrap();
+        fs::write(dir.path().join("Cargo.toml"), MEMBERS).unwrap();
+        fs::write(dir.path().join("aoc").join("Cargo.toml"), DEPS).unwrap();
+        fs::write(dir.path().join("aoc").join("src").join("days.rs"), DAYS).unwrap();
+        fs::create_dir(dir.path().join("day2")).unwrap();
+        dir
+    }
+
+    fn read(root: &Path, path: &str) -> String {
+        fs::read_to_string(root.join(path)).unwrap()
+    }
+
+    #[test]
+    fn test_new_day() {
+        let ws = workspace();
+        let root = ws.path();
+        new_day(root, 3, Some("Gear Ratios")).unwrap();
+
+        assert!(read(root, "Cargo.toml").contains("\"day2\",\n    \"day3\",\n    \"day10\","));
+        assert!(read(root, "aoc/Cargo.toml")
+            .contains("day2 = { path = \"../day2\" }\nday3 = { path = \"../day3\" }\nday10 ="));
+        assert!(read(root, "aoc/Cargo.toml")
+            .contains("\"day2/checked\",\n    \"day3/checked\",\n    \"day10/checked\","));
+        assert!(read(root, "aoc/src/days.rs").contains(
+            "Box::new(day2::Day2),\n        Box::new(day3::Day3),\n        Box::new(day10::Day10),"
+        ));
+        assert!(read(root, "day3/src/lib.rs").contains("/// Day 3: Gear Ratios\npub struct Day3;"));
+        assert!(read(root, "day3/src/main.rs").contains("run_main(&mut day3::Day3)"));
+        assert!(read(root, "day3/src/lib.rs").contains("assert_examples(&Day3, &[]);"));
+        assert!(read(root, "day3/Cargo.toml").contains("name = \"day3\""));
+        assert!(read(root, "day3/Cargo.toml").contains("checked = [\"common/checked\"]"));
+        assert_eq!(read(root, "day3/test.txt"), "");
+
+        // after the last day too
+        new_day(root, 25, None).unwrap();
+        assert!(read(root, "Cargo.toml").contains("\"day10\",\n    \"day25\",\n    \"grid\","));
+        assert!(read(root, "aoc/Cargo.toml").contains("day25 = { path = \"../day25\" }\nrand"));
+        assert!(read(root, "aoc/Cargo.toml").contains("\"day25/checked\",\n]"));
+    }
+
+    #[test]
+    fn test_new_day_refuses_existing_days() {
+        let ws = workspace();
+        let root = ws.path();
+        assert!(new_day(root, 2, None).is_err());
+        assert!(new_day(root, 10, None).is_err());
+        assert!(new_day(root, 26, None).is_err());
+        assert_eq!(read(root, "Cargo.toml"), MEMBERS);
+        assert_eq!(read(root, "aoc/src/days.rs"), DAYS);
+    }
+}
@@ -0,0 +1,129 @@
+//! Requests to the Advent of Code site on behalf of a logged in user
+
+use anyhow::{bail, Context, Result};
+use clap::Args;
+
+/// Year every day belongs to
+const YEAR: u16 = 2023;
+
+/// Options for talking to the site
+#[derive(Debug, Clone, Args)]
+pub struct SiteArgs {
+    /// Session cookie of your logged in Advent of Code account
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: String,
+    /// Site to talk to
+    #[arg(
+        long,
+        env = "AOC_URL",
+        default_value = "https://adventofcode.com",
+        hide = true
+    )]
+    pub url: String,
+}
+
+impl SiteArgs {
+    pub fn site(self) -> Site {
+        Site {
+            base_url: self.url,
+            session: self.session,
+        }
+    }
+}
+
+/// The site at `base_url`, as the user the `session` cookie belongs to
+pub struct Site {
+    pub base_url: String,
+    pub session: String,
+}
+
+impl Site {
+    /// The page at `path` of this year's event, e.g. `day/1/input`
+    pub fn get(&self, path: &str) -> Result<String> {
+        let url = self.url(path);
+        self.send(&url, self.request("GET", &url).call())
+    }
+
+    /// Post `form` to `path` of this year's event and return the page it answers with
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let url = self.url(path);
+        self.send(&url, self.request("POST", &url).send_form(form))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{YEAR}/{path}", self.base_url)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        ureq::request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set(
+                "User-Agent",
+                concat!("aoc-2023-runner/", env!("CARGO_PKG_VERSION")),
+            )
+    }
+
+    fn send(&self, url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .with_context(|| format!("unable to read the response from {url}")),
+            // the site answers 400 for a bad session and 404 for days that aren't unlocked yet
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                bail!(
+                    "{url} returned {status}: {}",
+                    body.lines().next().unwrap_or("")
+                )
+            }
+            Err(e) => Err(e).with_context(|| format!("unable to reach {url}")),
+        }
+    }
+}
+
+/// A stand-in for the site on a local port, so tests never touch the network
+#[cfg(test)]
+pub mod stand_in {
+    use super::Site;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use tiny_http::{Response, Server};
+
+    pub const SESSION: &str = "53616c7465645f5f";
+
+    /// Serve requests with `respond(method, url, body) -> (status, page)`. Requests without the
+    /// right session cookie get a 400 like on the real site. Returns the site as `session` along
+    /// with a count of the requests made to it.
+    pub fn serve(
+        session: &str,
+        respond: impl Fn(&str, &str, &str) -> (u16, String) + Send + 'static,
+    ) -> (Site, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let site = Site {
+            base_url: format!("http://{}", server.server_addr()),
+            session: session.to_string(),
+        };
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let cookie = format!("session={SESSION}");
+                let authorized = request
+                    .headers()
+                    .iter()
+                    .any(|h| h.field.equiv("Cookie") && h.value.as_str() == cookie);
+                let mut body = String::new();
+                let _ = request.as_reader().read_to_string(&mut body);
+                let (status, page) = if authorized {
+                    respond(request.method().as_str(), request.url(), &body)
+                } else {
+                    (400, "Puzzle inputs differ by user.".to_string())
+                };
+                let _ = request.respond(Response::from_string(page).with_status_code(status));
+            }
+        });
+        (site, requests)
+    }
+}
@@ -0,0 +1,247 @@
+//! Submit answers to the site, keeping a history of what it said so a known wrong answer is never
+//! sent twice
+
+use crate::days;
+use crate::site::Site;
+use anyhow::{anyhow, bail, Result};
+use common::answers::Answers;
+use common::history::{History, Submission, Verdict};
+use common::{input, Part};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// What the site answered to a submission
+#[derive(Debug, PartialEq, Eq)]
+pub enum Reply {
+    Judged(Verdict),
+    /// Answers are rate limited; this is how long until the next one is accepted
+    TooSoon(Option<Duration>),
+    /// The part was already solved, so the answer wasn't checked
+    AlreadySolved,
+    /// A page we don't know how to read, as text
+    Unrecognized(String),
+}
+
+/// The result of trying to submit an answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The history already had a verdict for the answer so it wasn't sent
+    Known(Verdict),
+    Sent(Reply),
+}
+
+/// Text of the `<article>` holding the message on a reply page, without markup
+fn article_text(page: &str) -> String {
+    let article = match (page.find("<article>"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// A wait like `1m 2s`
+fn parse_wait(wait: &str) -> Option<Duration> {
+    let mut secs = 0;
+    for part in wait.split_whitespace() {
+        let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Read the page the site answers a submission with
+pub fn parse_reply(page: &str) -> Reply {
+    let text = article_text(page);
+    if text.contains("That's the right answer") {
+        Reply::Judged(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        Reply::Judged(if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait));
+        Reply::TooSoon(wait)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Reply::AlreadySolved
+    } else {
+        Reply::Unrecognized(text)
+    }
+}
+
+/// Posts answers to the site, recording every verdict in the history at `history`
+pub struct Submitter {
+    pub site: Site,
+    pub history: PathBuf,
+}
+
+impl Submitter {
+    /// Submit `answer` for `part` of `day`, unless the history already knows what the site will
+    /// say about it. `hash` is the hash of the input the answer was computed from.
+    pub fn submit(&self, day: u8, part: Part, hash: &str, answer: &str) -> Result<Outcome> {
+        let mut history = History::load(&self.history)?;
+        if let Some(verdict) = history.known(day, hash, part, answer) {
+            return Ok(Outcome::Known(verdict));
+        }
+
+        let level = match part {
+            Part::One => "1",
+            Part::Two => "2",
+        };
+        let page = self.site.post(
+            &format!("day/{day}/answer"),
+            &[("level", level), ("answer", answer)],
+        )?;
+        let reply = parse_reply(&page);
+
+        if let Reply::Judged(verdict) = reply {
+            let answer = answer.to_string();
+            history.insert(day, hash, part, Submission { answer, verdict });
+            history.save(&self.history)?;
+        }
+        Ok(Outcome::Sent(reply))
+    }
+}
+
+/// Solve `part` of `day` against its default input and submit the answer. A correct answer is
+/// added to the answers manifest at `manifest`.
+pub fn submit(day: u8, part: Part, submitter: &Submitter, manifest: &Path) -> Result<()> {
+    let puzzle = days::find(day).ok_or_else(|| anyhow!("day {day} is not solved yet"))?;
+    let input = input::read(&input::default_path(day))?;
+    let hash = input::hash(&input);
+    let answer = puzzle.solve(&input, part)?;
+    println!("Day {day} part {part}: {answer}");
+
+    match submitter.submit(day, part, &hash, &answer)? {
+        Outcome::Known(Verdict::Correct) => println!("Already accepted, not submitted again"),
+        Outcome::Known(verdict) => bail!("not submitted: {answer} is known to be {verdict}"),
+        Outcome::Sent(Reply::Judged(Verdict::Correct)) => {
+            let mut answers = Answers::load(manifest)?;
+            answers.insert(day, &hash, part, answer);
+            answers.save(manifest)?;
+            println!("Correct! Recorded in {}", manifest.display());
+        }
+        Outcome::Sent(Reply::Judged(verdict)) => bail!("{answer} is {verdict}"),
+        Outcome::Sent(Reply::TooSoon(Some(wait))) => {
+            bail!("answered too recently, wait {}s", wait.as_secs())
+        }
+        Outcome::Sent(Reply::TooSoon(None)) => bail!("answered too recently"),
+        Outcome::Sent(Reply::AlreadySolved) => println!("Already solved on the site"),
+        Outcome::Sent(Reply::Unrecognized(text)) => bail!("unrecognized reply: {text}"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::stand_in::{serve, SESSION};
+    use std::sync::atomic::Ordering;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        let correct = page("That's the right answer!  You are <span>one gold star</span> closer.");
+        assert_eq!(parse_reply(&correct), Reply::Judged(Verdict::Correct));
+        let high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(parse_reply(&high), Reply::Judged(Verdict::TooHigh));
+        let low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(parse_reply(&low), Reply::Judged(Verdict::TooLow));
+        let wrong =
+            page("That's not the right answer.  If you're stuck, there are some general tips.");
+        assert_eq!(parse_reply(&wrong), Reply::Judged(Verdict::Wrong));
+        let soon = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 2s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a>");
+        assert_eq!(
+            parse_reply(&soon),
+            Reply::TooSoon(Some(Duration::from_secs(62)))
+        );
+        let solved =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_reply(&solved), Reply::AlreadySolved);
+        assert_eq!(
+            parse_reply("<p>Something else</p>"),
+            Reply::Unrecognized("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn test_never_resubmits_wrong_answers() {
+        let (site, requests) = serve(SESSION, |method, url, body| {
+            assert_eq!((method, url), ("POST", "/2023/day/1/answer"));
+            let message = match body {
+                "level=1&answer=100" => "That's not the right answer; your answer is too high.",
+                "level=1&answer=42" => "That's the right answer!",
+                _ => panic!("unexpected submission {body}"),
+            };
+            (200, page(message))
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let submitter = Submitter {
+            site,
+            history: dir.path().join("history.toml"),
+        };
+        let submit = |answer| submitter.submit(1, Part::One, "abc", answer).unwrap();
+
+        assert_eq!(
+            submit("100"),
+            Outcome::Sent(Reply::Judged(Verdict::TooHigh))
+        );
+        assert_eq!(submit("100"), Outcome::Known(Verdict::TooHigh));
+        assert_eq!(submit("250"), Outcome::Known(Verdict::TooHigh));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert_eq!(submit("42"), Outcome::Sent(Reply::Judged(Verdict::Correct)));
+        assert_eq!(submit("42"), Outcome::Known(Verdict::Correct));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        // the history is kept between runs
+        let history = History::load(&submitter.history).unwrap();
+        assert_eq!(history.get(1, "abc", Part::One).len(), 2);
+    }
+
+    #[test]
+    fn test_rate_limited_answers_are_not_recorded() {
+        let (site, requests) = serve(SESSION, |_, _, _| {
+            (200, page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 36s left to wait."))
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let submitter = Submitter {
+            site,
+            history: dir.path().join("history.toml"),
+        };
+
+        for _ in 0..2 {
+            assert_eq!(
+                submitter.submit(2, Part::Two, "abc", "7").unwrap(),
+                Outcome::Sent(Reply::TooSoon(Some(Duration::from_secs(36))))
+            );
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        assert!(!submitter.history.exists());
+    }
+}
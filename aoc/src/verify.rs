@@ -0,0 +1,95 @@
+//! Check every day's answers against the answers manifest
+
+use crate::days;
+use anyhow::{bail, Result};
+use common::answers::Answers;
+use common::{input, Part};
+use rayon::prelude::*;
+use std::path::Path;
+
+/// Outcome of checking one part of one day
+enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing(String),
+    Error(anyhow::Error),
+}
+
+/// Solve every day against its default input and compare with `manifest`. With `record`, answers
+/// missing from the manifest are added to it.
+pub fn verify(manifest: &Path, record: bool) -> Result<()> {
+    let mut answers = Answers::load(manifest)?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    // solve every day at once, then check them in order
+    let puzzles = days::all();
+    let solved: Vec<Result<_>> = puzzles
+        .par_iter()
+        .map(|puzzle| {
+            let input = input::read(&input::default_path(puzzle.day()))?;
+            let results = Part::ALL.map(|part| puzzle.solve(&input, part));
+            Ok((input::hash(&input), results))
+        })
+        .collect();
+
+    for (puzzle, solved) in puzzles.iter().zip(solved) {
+        let day = puzzle.day();
+        // a day without its input fails, the others are still checked
+        let (hash, results) = match solved {
+            Ok(solved) => solved,
+            Err(e) => {
+                failed += Part::ALL.len();
+                println!("Day {day:>2}: ERROR ({e:#})");
+                continue;
+            }
+        };
+
+        for (part, result) in Part::ALL.into_iter().zip(results) {
+            let verdict = match result {
+                Err(e) => Verdict::Error(e),
+                Ok(actual) => match answers.get(day, &hash, part) {
+                    None => Verdict::Missing(actual),
+                    Some(expected) if expected == actual => Verdict::Pass,
+                    Some(expected) => Verdict::Fail {
+                        expected: expected.to_string(),
+                        actual,
+                    },
+                },
+            };
+
+            match verdict {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("Day {day:>2} part {part}: pass");
+                }
+                Verdict::Fail { expected, actual } => {
+                    failed += 1;
+                    println!("Day {day:>2} part {part}: FAIL (expected {expected}, got {actual})");
+                }
+                Verdict::Missing(actual) => {
+                    missing += 1;
+                    println!("Day {day:>2} part {part}: missing (got {actual})");
+                    if record {
+                        answers.insert(day, &hash, part, actual);
+                    }
+                }
+                Verdict::Error(e) => {
+                    failed += 1;
+                    println!("Day {day:>2} part {part}: ERROR ({e:#})");
+                }
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+
+    if record && missing > 0 {
+        answers.save(manifest)?;
+        println!("Recorded {missing} answers in {}", manifest.display());
+    }
+
+    if failed > 0 {
+        bail!("{failed} answers did not verify");
+    }
+    Ok(())
+}
@@ -0,0 +1,389 @@
+//! Rebuild and rerun a day against its examples and input whenever its sources or inputs change,
+//! showing how the answers moved since the previous run
+
+use anyhow::{bail, Context, Result};
+use common::config::Config;
+use common::workspace::{self, day_key};
+use common::Part;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::{BTreeMap, HashSet};
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+/// How long to wait for the rest of the events an editor's save makes before rerunning
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// What a run of one part on one input gave
+#[derive(Debug, Clone, PartialEq)]
+enum Outcome {
+    Answer(String),
+    Failed(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{answer}"),
+            Outcome::Failed(e) => write!(f, "error: {e}"),
+        }
+    }
+}
+
+/// Every outcome of a run, keyed by the input's label and the part
+type Run = Vec<((String, Part), Outcome)>;
+
+/// The day's examples, its test*.txt files in order
+fn examples(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut examples = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("unable to read {}", dir.display()))? {
+        let path = entry?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if name.starts_with("test") && name.ends_with(".txt") {
+            examples.push(path);
+        }
+    }
+    examples.sort();
+    Ok(examples)
+}
+
+/// The answer to `part` of `day` in what the day's binary printed
+fn answer(stdout: &str, day: u8, part: Part) -> Option<String> {
+    let prefix = format!("Day {day} part {part}: ");
+    stdout
+        .lines()
+        .find_map(|l| l.strip_prefix(&prefix))
+        .map(String::from)
+}
+
+/// Why the day's binary failed, from what it printed to stderr
+fn failure(output: &Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let mut lines = stderr.lines();
+    while let Some(line) = lines.next() {
+        if let Some(e) = line.strip_prefix("Error: ") {
+            return e.to_string();
+        }
+        if let Some((_, at)) = line.split_once("panicked at ") {
+            let at = at.trim_end_matches(':');
+            return match lines.next() {
+                Some(message) => format!("panicked at {at}: {message}"),
+                None => format!("panicked at {at}"),
+            };
+        }
+    }
+    format!("exited with {}", output.status)
+}
+
+/// What changed between `previous` and `current`, a line per answer: `~` for one that changed,
+/// `+` for a new one and `-` for one that's gone
+fn diff(previous: Option<&Run>, current: &Run) -> Vec<String> {
+    let line = |mark: char, (label, part): &(String, Part), outcome: &Outcome| {
+        format!("{mark} {label} part {part}: {outcome}")
+    };
+    let Some(previous) = previous else {
+        return current.iter().map(|(k, o)| line(' ', k, o)).collect();
+    };
+    let before: BTreeMap<_, _> = previous.iter().map(|(k, o)| (k, o)).collect();
+
+    let mut lines: Vec<String> = current
+        .iter()
+        .map(|(key, outcome)| match before.get(key) {
+            None => line('+', key, outcome),
+            Some(&was) if was == outcome => line(' ', key, outcome),
+            Some(was) => format!("{} (was {was})", line('~', key, outcome)),
+        })
+        .collect();
+    lines.extend(
+        previous
+            .iter()
+            .filter(|(key, _)| !current.iter().any(|(k, _)| k == key))
+            .map(|(key, outcome)| line('-', key, outcome)),
+    );
+    lines
+}
+
+/// `path` without `..`s and links, as the watcher reports paths, if it exists
+fn resolved(path: &Path) -> PathBuf {
+    path.canonicalize()
+        .or_else(|_| std::path::absolute(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Builds a day's binary with cargo and runs it
+struct Day {
+    day: u8,
+    root: PathBuf,
+    dir: PathBuf,
+    config: PathBuf,
+}
+
+impl Day {
+    fn cargo(&self) -> Command {
+        let mut cargo = Command::new(std::env::var_os("CARGO").unwrap_or(OsString::from("cargo")));
+        cargo.current_dir(&self.root);
+        cargo
+    }
+
+    /// Rebuild the day, with cargo's messages going to the terminal
+    fn build(&self) -> Result<bool> {
+        let status = self
+            .cargo()
+            .args(["build", "--release", "-q", "-p"])
+            .arg(day_key(self.day))
+            .status()
+            .context("unable to run cargo")?;
+        Ok(status.success())
+    }
+
+    /// Where `build` puts the day's binary
+    fn binary(&self) -> PathBuf {
+        let target = std::env::var_os("CARGO_TARGET_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| self.root.join("target"));
+        target
+            .join("release")
+            .join(day_key(self.day) + std::env::consts::EXE_SUFFIX)
+    }
+
+    /// Solve `part` of `input` with the day's binary
+    fn solve(&self, input: &Path, part: Part) -> Result<Outcome> {
+        let binary = self.binary();
+        let output = Command::new(&binary)
+            .current_dir(&self.root)
+            .arg("--part")
+            .arg(part.to_string())
+            .arg("--input")
+            .arg(input)
+            .arg("--config")
+            .arg(&self.config)
+            .env("RUST_LIB_BACKTRACE", "0")
+            .output()
+            .with_context(|| format!("unable to run {}", binary.display()))?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(match answer(&stdout, self.day, part) {
+            Some(answer) if output.status.success() => Outcome::Answer(answer),
+            _ => Outcome::Failed(failure(&output)),
+        })
+    }
+
+    /// Solve both parts of every example and then the input, or None if the day doesn't build
+    fn run(&self) -> Result<Option<Run>> {
+        if !self.build()? {
+            return Ok(None);
+        }
+        let config = Config::load(&self.config)?;
+        let input = resolved(&config.input(self.day));
+
+        let mut run = Run::new();
+        for path in examples(&self.dir)?.into_iter().chain([input.clone()]) {
+            let label = path.strip_prefix(&self.dir).unwrap_or(&path);
+            let label = label.display().to_string();
+            for part in Part::ALL {
+                let mut outcome = self.solve(&path, part)?;
+                // answers the config expects are for the day's input, not its examples
+                if let Outcome::Answer(answer) = &outcome {
+                    if path == input {
+                        if let Err(e) = config.check(self.day, part, answer) {
+                            outcome = Outcome::Failed(e.to_string());
+                        }
+                    }
+                }
+                run.push(((label.clone(), part), outcome));
+            }
+        }
+        Ok(Some(run))
+    }
+
+    /// Whether a change to `path` can change the answers
+    fn affects(&self, path: &Path, input: &Path) -> bool {
+        // editors' swap and backup files
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if name.starts_with('.') || name.ends_with('~') {
+            return false;
+        }
+        path.starts_with(&self.dir) || path == input || path == self.config
+    }
+}
+
+/// The day's input according to the config, if the config can be read
+fn input(day: &Day) -> Option<PathBuf> {
+    Config::load(&day.config)
+        .ok()
+        .map(|config| resolved(&config.input(day.day)))
+}
+
+/// Block until a change that affects `day`, then until the changes stop coming
+fn wait(day: &Day, events: &Receiver<notify::Result<Event>>) -> Result<()> {
+    // with a broken config only changes to the crate and the config itself count
+    let input = input(day).unwrap_or_default();
+    let affects = |event: notify::Result<Event>| match event {
+        Ok(event) => {
+            let changed = matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            );
+            changed && event.paths.iter().any(|p| day.affects(p, &input))
+        }
+        Err(e) => {
+            println!("error: {e}");
+            false
+        }
+    };
+
+    while !affects(events.recv()?) {}
+    while events.recv_timeout(SETTLE).is_ok() {}
+    Ok(())
+}
+
+/// Watch `day`'s crate, its input and the config at `config`, rerunning the day on every change
+pub fn watch(day: u8, config: &Path) -> Result<()> {
+    let root = resolved(&workspace::root());
+    let dir = root.join(day_key(day));
+    if !dir.is_dir() {
+        bail!("there is no {}", dir.display());
+    }
+    let config = resolved(config);
+    let day = Day {
+        day,
+        root,
+        dir,
+        config,
+    };
+
+    let (tx, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(&day.dir, RecursiveMode::Recursive)?;
+    let mut watched = HashSet::new();
+
+    let mut previous = None;
+    loop {
+        // watch the directories of files outside the crate, so replacing a file is seen too, and
+        // again whenever the config moves the input
+        for path in input(&day).iter().chain([&day.config]) {
+            if let Some(parent) = path.parent().filter(|p| !p.starts_with(&day.dir)) {
+                if !watched.contains(parent) {
+                    match watcher.watch(parent, RecursiveMode::NonRecursive) {
+                        Ok(()) => {
+                            watched.insert(parent.to_path_buf());
+                        }
+                        Err(e) => println!("error: unable to watch {}: {e}", parent.display()),
+                    }
+                }
+            }
+        }
+
+        println!("Running day {}", day.day);
+        match day.run() {
+            Ok(Some(run)) => {
+                for line in diff(previous.as_ref(), &run) {
+                    println!("{line}");
+                }
+                previous = Some(run);
+            }
+            Ok(None) => println!("Day {} doesn't build", day.day),
+            // e.g. a typo in the config, which the next change can fix
+            Err(e) => println!("error: {e:#}"),
+        }
+        println!("Watching {} for changes", day.dir.display());
+        wait(&day, &events)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(outcomes: &[(&str, Part, Outcome)]) -> Run {
+        outcomes
+            .iter()
+            .map(|(label, part, outcome)| ((label.to_string(), *part), outcome.clone()))
+            .collect()
+    }
+
+    fn answer(a: &str) -> Outcome {
+        Outcome::Answer(a.to_string())
+    }
+
+    #[test]
+    fn test_diff() {
+        let first = run(&[
+            ("test1.txt", Part::One, answer("142")),
+            ("test1.txt", Part::Two, answer("142")),
+            ("test2.txt", Part::One, Outcome::Failed("no digits".into())),
+            ("input.txt", Part::One, answer("54667")),
+        ]);
+        assert_eq!(
+            diff(None, &first),
+            [
+                "  test1.txt part 1: 142",
+                "  test1.txt part 2: 142",
+                "  test2.txt part 1: error: no digits",
+                "  input.txt part 1: 54667",
+            ]
+        );
+
+        let second = run(&[
+            ("test1.txt", Part::One, answer("142")),
+            ("test1.txt", Part::Two, answer("281")),
+            ("test2.txt", Part::One, answer("209")),
+            ("input.txt", Part::Two, answer("54203")),
+        ]);
+        assert_eq!(
+            diff(Some(&first), &second),
+            [
+                "  test1.txt part 1: 142",
+                "~ test1.txt part 2: 281 (was 142)",
+                "~ test2.txt part 1: 209 (was error: no digits)",
+                "+ input.txt part 2: 54203",
+                "- input.txt part 1: 54667",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_output() {
+        let stdout = "Day 8 part 1: 2\nDay 8 part 2: 6\n";
+        assert_eq!(super::answer(stdout, 8, Part::Two).as_deref(), Some("6"));
+        assert_eq!(super::answer(stdout, 9, Part::Two), None);
+
+        let output = |stderr: &str| Output {
+            status: Command::new("false").status().unwrap(),
+            stdout: Vec::new(),
+            stderr: stderr.as_bytes().to_vec(),
+        };
+        assert_eq!(
+            failure(&output("[WARN] slow\nError: day 8: no node 'AAA'\n")),
+            "day 8: no node 'AAA'"
+        );
+        assert_eq!(
+            failure(&output(
+                "thread 'main' panicked at day8/src/lib.rs:40:9:\nindex out of bounds\n"
+            )),
+            "panicked at day8/src/lib.rs:40:9: index out of bounds"
+        );
+        assert!(failure(&output("")).starts_with("exited with"));
+    }
+
+    #[test]
+    fn test_examples() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "test2.txt",
+            "input.txt",
+            "test1.txt",
+            "notes.txt",
+            "test3.md",
+        ] {
+            fs::write(dir.path().join(name), "").unwrap();
+        }
+        assert_eq!(
+            examples(dir.path()).unwrap(),
+            [dir.path().join("test1.txt"), dir.path().join("test2.txt")]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+//! Manifest of known correct answers, stored per day and keyed by a hash of the input they were
+//! computed from
+
+use crate::workspace::{self, day_key};
+use crate::Part;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Known answers for one input
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Expected {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+/// The answers manifest: `[dayN.<input hash>]` tables holding `part1`/`part2`
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, Expected>>,
+}
+
+/// The manifest checked in at the root of the workspace
+pub fn default_path() -> PathBuf {
+    workspace::root().join("answers.toml")
+}
+
+impl Answers {
+    /// Load a manifest; a manifest that doesn't exist yet is empty
+    pub fn load(path: &Path) -> Result<Self> {
+        workspace::load_toml(path, "answers")
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        workspace::save_toml(path, self)
+    }
+
+    /// The known answer for `part` of `day` with the input hashing to `hash`
+    pub fn get(&self, day: u8, hash: &str, part: Part) -> Option<&str> {
+        self.days.get(&day_key(day))?.get(hash)?.get(part)
+    }
+
+    pub fn insert(&mut self, day: u8, hash: &str, part: Part, answer: String) {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(hash.to_string())
+            .or_default()
+            .set(part, answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(5, "abc", Part::One, "35".to_string());
+        answers.insert(5, "abc", Part::Two, "46".to_string());
+        answers.insert(11, "def", Part::Two, "82000210".to_string());
+
+        let raw = toml::to_string(&answers).unwrap();
+        let answers: Answers = toml::from_str(&raw).unwrap();
+
+        assert_eq!(answers.get(5, "abc", Part::One), Some("35"));
+        assert_eq!(answers.get(5, "abc", Part::Two), Some("46"));
+        assert_eq!(answers.get(11, "def", Part::One), None);
+        assert_eq!(answers.get(11, "def", Part::Two), Some("82000210"));
+        assert_eq!(answers.get(5, "def", Part::One), None);
+    }
+}
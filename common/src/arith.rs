@@ -0,0 +1,118 @@
+//! Arithmetic on numbers that come from the input. It's plain arithmetic unless built with the
+//! `checked` feature, which checks every operation and turns an overflow into an error naming the
+//! day and what was being computed, instead of a panic or a wrapped answer.
+
+use crate::Error;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, One, Zero};
+use std::fmt::Display;
+
+/// Whether this build checks for overflow
+pub const CHECKED: bool = cfg!(feature = "checked");
+
+fn overflow(day: u8, what: &str, a: impl Display, op: &str, b: impl Display) -> Error {
+    Error::Overflow {
+        day,
+        operation: format!("{what} ({a} {op} {b})"),
+    }
+}
+
+/// `a + b`, where `what` says what's being computed
+pub fn add<T: CheckedAdd + Display>(day: u8, what: &str, a: T, b: T) -> Result<T, Error> {
+    if CHECKED {
+        a.checked_add(&b)
+            .ok_or_else(|| overflow(day, what, &a, "+", &b))
+    } else {
+        Ok(a + b)
+    }
+}
+
+/// `a - b`, where `what` says what's being computed
+pub fn sub<T: CheckedSub + Display>(day: u8, what: &str, a: T, b: T) -> Result<T, Error> {
+    if CHECKED {
+        a.checked_sub(&b)
+            .ok_or_else(|| overflow(day, what, &a, "-", &b))
+    } else {
+        Ok(a - b)
+    }
+}
+
+/// `a * b`, where `what` says what's being computed
+pub fn mul<T: CheckedMul + Display>(day: u8, what: &str, a: T, b: T) -> Result<T, Error> {
+    if CHECKED {
+        a.checked_mul(&b)
+            .ok_or_else(|| overflow(day, what, &a, "*", &b))
+    } else {
+        Ok(a * b)
+    }
+}
+
+/// `base` to the power of `exp`, where `what` says what's being computed
+pub fn pow<T: CheckedMul + One + Clone + Display>(
+    day: u8,
+    what: &str,
+    base: T,
+    exp: usize,
+) -> Result<T, Error> {
+    if CHECKED {
+        num_traits::checked_pow(base.clone(), exp)
+            .ok_or_else(|| overflow(day, what, base, "^", exp))
+    } else {
+        Ok(num_traits::pow(base, exp))
+    }
+}
+
+/// The sum of `values`, where `what` says what's being added up
+pub fn sum<T: CheckedAdd + Zero + Display>(
+    day: u8,
+    what: &str,
+    values: impl IntoIterator<Item = T>,
+) -> Result<T, Error> {
+    values
+        .into_iter()
+        .try_fold(T::zero(), |total, v| add(day, what, total, v))
+}
+
+/// The product of `values`, where `what` says what's being multiplied
+pub fn product<T: CheckedMul + One + Display>(
+    day: u8,
+    what: &str,
+    values: impl IntoIterator<Item = T>,
+) -> Result<T, Error> {
+    values
+        .into_iter()
+        .try_fold(T::one(), |total, v| mul(day, what, total, v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arith() {
+        assert_eq!(add(1, "sum", 2, 3), Ok(5));
+        assert_eq!(sub(1, "difference", 2u64, 3u64 - 1), Ok(0));
+        assert_eq!(mul(1, "product", -4i64, 5), Ok(-20));
+        assert_eq!(pow(1, "points", 2i64, 10), Ok(1024));
+        assert_eq!(sum(1, "total", [1u8, 2, 3]), Ok(6));
+        assert_eq!(product(1, "ways", [2u32, 3, 4]), Ok(24));
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn test_overflow() {
+        assert_eq!(
+            mul(6, "ways to win", 70_000i32, 40_000)
+                .unwrap_err()
+                .to_string(),
+            "day 6: overflow in ways to win (70000 * 40000)"
+        );
+        assert_eq!(
+            sum(3, "part numbers", [u8::MAX, 1])
+                .unwrap_err()
+                .to_string(),
+            "day 3: overflow in part numbers (255 + 1)"
+        );
+        assert!(sub(5, "range", 1u64, 2).is_err());
+        assert!(pow(4, "points", 2i64, 64).is_err());
+    }
+}
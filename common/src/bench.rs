@@ -0,0 +1,136 @@
+//! Timing of parsing and solving, including any alternative implementations a day registers
+
+use crate::{Part, Solution};
+use anyhow::Result;
+use std::time::{Duration, Instant};
+
+/// Signature shared by both parts and any alternatives to them
+pub type SolveFn<S> = fn(&S, &<S as Solution>::Input) -> Result<String>;
+
+/// Another implementation of one part of a [`Solution`], e.g. a naive version kept around to
+/// compare against the one actually used
+pub struct Alternative<S: Solution + ?Sized> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: SolveFn<S>,
+}
+
+/// Summary of repeated timings
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self {
+                mean: Duration::ZERO,
+                min: Duration::ZERO,
+                stddev: Duration::ZERO,
+            };
+        }
+
+        let secs = samples
+            .iter()
+            .map(Duration::as_secs_f64)
+            .collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / secs.len() as f64;
+
+        Self {
+            mean: Duration::from_secs_f64(mean),
+            min: samples.iter().min().copied().unwrap_or_default(),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timings for one implementation of one part
+#[derive(Debug, Clone)]
+pub struct PartBench {
+    pub part: Part,
+    /// None for the implementation the day actually uses, otherwise the alternative's name
+    pub name: Option<&'static str>,
+    pub answer: String,
+    pub stats: Stats,
+}
+
+/// Timings for a whole day
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub parse: Stats,
+    pub parts: Vec<PartBench>,
+}
+
+/// Run `f` `iterations` times (at least once), returning the last result and every duration
+fn time<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<(T, Vec<Duration>)> {
+    let mut samples = Vec::with_capacity(iterations);
+    loop {
+        let start = Instant::now();
+        let value = f()?;
+        samples.push(start.elapsed());
+        if samples.len() >= iterations {
+            return Ok((value, samples));
+        }
+    }
+}
+
+/// Time parsing and every implementation of each part over `iterations` runs
+pub fn run<S: Solution + ?Sized>(
+    solution: &S,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Report> {
+    let (parsed, parse_samples) = time(iterations, || solution.parse(input))?;
+
+    let mut implementations: Vec<(Part, Option<&'static str>, SolveFn<S>)> =
+        vec![(Part::One, None, S::part1), (Part::Two, None, S::part2)];
+    for alt in solution.alternatives() {
+        implementations.push((alt.part, Some(alt.name), alt.solve));
+    }
+
+    let mut bench = Vec::new();
+    for part in parts {
+        for (_, name, solve) in implementations.iter().filter(|(p, _, _)| p == part) {
+            let (answer, samples) = time(iterations, || solve(solution, &parsed))?;
+            bench.push(PartBench {
+                part: *part,
+                name: *name,
+                answer,
+                stats: Stats::new(&samples),
+            });
+        }
+    }
+
+    Ok(Report {
+        day: S::DAY,
+        parse: Stats::new(&parse_samples),
+        parts: bench,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_millis);
+        let stats = Stats::new(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.mean.as_micros(), 5000);
+        assert_eq!(stats.stddev.as_micros(), 2000);
+    }
+
+    #[test]
+    fn test_stats_empty() {
+        let stats = Stats::new(&[]);
+        assert_eq!(stats.mean, Duration::ZERO);
+    }
+}
@@ -0,0 +1,151 @@
+//! Command line handling shared by `aoc run` and the per-day binaries
+
+use crate::config::{self, Config};
+use crate::output::Format;
+use crate::{input, Part, Puzzle};
+use anyhow::Result;
+use clap::{ArgAction, Args, Parser};
+use log::LevelFilter;
+use std::path::PathBuf;
+
+/// How much debugging output the days print to stderr
+#[derive(Debug, Clone, Copy, Args)]
+pub struct Verbosity {
+    /// Print debugging output to stderr: -v for summaries, -vv for every step
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+}
+
+impl Verbosity {
+    pub fn level(&self) -> LevelFilter {
+        match self.verbose {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        }
+    }
+
+    /// Send log output at the chosen level to stderr. Only answers go to stdout.
+    pub fn init(&self) {
+        env_logger::Builder::new()
+            .filter_level(self.level())
+            .format_timestamp(None)
+            .init();
+    }
+}
+
+/// Where the project config is
+#[derive(Debug, Clone, Args)]
+pub struct ConfigArgs {
+    /// Project config with inputs, parameters and expected answers [default: aoc.toml at the
+    /// workspace root]
+    #[arg(long, value_name = "PATH", global = true)]
+    pub config: Option<PathBuf>,
+}
+
+impl ConfigArgs {
+    pub fn load(&self) -> Result<Config> {
+        match &self.config {
+            Some(path) => Config::load(path),
+            None => Config::load(&config::default_path()),
+        }
+    }
+}
+
+/// Options for solving a single day
+#[derive(Debug, Clone, Args)]
+pub struct RunArgs {
+    /// Only solve this part (1 or 2); both parts are solved by default
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// Puzzle input to solve, `-` reads stdin [default: the day's input in the config, or its
+    /// input.txt]
+    #[arg(short, long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    /// How to print the answers: `text` lines, or `json` objects with the input's hash and the
+    /// parse and solve times
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+}
+
+impl RunArgs {
+    /// The parts selected on the command line
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    /// Read the selected input for `day`
+    pub fn read_input(&self, day: u8, config: &Config) -> Result<String> {
+        match &self.input {
+            Some(path) => input::read(path),
+            None => input::read(&config.input(day)),
+        }
+    }
+
+    /// Check `answer` against the answer `config` expects, which is for the day's own input
+    pub fn check(&self, day: u8, part: Part, answer: &str, config: &Config) -> Result<()> {
+        match self.input {
+            Some(_) => Ok(()),
+            None => config.check(day, part, answer),
+        }
+    }
+}
+
+/// Command line of a single day's binary
+#[derive(Parser)]
+struct DayCli {
+    #[command(flatten)]
+    run: RunArgs,
+    #[command(flatten)]
+    config: ConfigArgs,
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+/// Solve the selected parts of `puzzle` and print the answers, failing on one that isn't what
+/// `config` expects
+pub fn solve(puzzle: &dyn Puzzle, args: &RunArgs, config: &Config) -> Result<()> {
+    let input = args.read_input(puzzle.day(), config)?;
+    for part in args.parts() {
+        let solved = puzzle.solve_timed(&input, part)?;
+        println!("{}", solved.line(args.format)?);
+        args.check(puzzle.day(), part, &solved.answer, config)?;
+    }
+    Ok(())
+}
+
+/// Entry point for the per-day binaries
+pub fn run_main(puzzle: &mut dyn Puzzle) -> Result<()> {
+    let cli = DayCli::parse();
+    cli.verbosity.init();
+    let config = cli.config.load()?;
+    config.configure(puzzle)?;
+    solve(puzzle, &cli.run, &config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity() {
+        let level = |args: &[&str]| DayCli::try_parse_from(args).unwrap().verbosity.level();
+        assert_eq!(level(&["day6"]), LevelFilter::Warn);
+        assert_eq!(level(&["day6", "-v"]), LevelFilter::Debug);
+        assert_eq!(level(&["day6", "-vv", "--part", "2"]), LevelFilter::Trace);
+        assert_eq!(level(&["day6", "-vvv"]), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn test_format() {
+        let format = |args: &[&str]| DayCli::try_parse_from(args).map(|cli| cli.run.format);
+        assert_eq!(format(&["day9"]).unwrap(), Format::Text);
+        assert_eq!(format(&["day9", "--format", "json"]).unwrap(), Format::Json);
+        assert!(format(&["day9", "--format", "yaml"]).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+//! The project config, `aoc.toml`: per day, an input to solve instead of its input.txt, the
+//! answers that input should give and the puzzle's parameters
+
+use crate::workspace::{self, day_key};
+use crate::{input, Part, Puzzle};
+use anyhow::{bail, Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+pub use toml::Table;
+
+/// Settings of one day, a `[dayN]` table
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayConfig {
+    /// Input to solve instead of the day's input.txt, relative to the config
+    pub input: Option<PathBuf>,
+    /// Answers the day's input should give
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    /// The puzzle's parameters, as documented by the day's `Solution`
+    #[serde(default)]
+    pub params: Table,
+}
+
+impl DayConfig {
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// The project config: a `[dayN]` table for each day that doesn't use the defaults
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Config {
+    days: BTreeMap<String, DayConfig>,
+}
+
+/// The config at the root of the workspace
+pub fn default_path() -> PathBuf {
+    workspace::root().join("aoc.toml")
+}
+
+/// `params` as the parameters of `day`
+pub fn params<P: DeserializeOwned>(day: u8, params: Table) -> Result<P> {
+    toml::Value::Table(params)
+        .try_into()
+        .with_context(|| format!("invalid parameters for day {day}"))
+}
+
+impl Config {
+    /// Load a config; a config that doesn't exist leaves every day at its defaults
+    pub fn load(path: &Path) -> Result<Self> {
+        let Some(raw) = workspace::read_optional(path)? else {
+            return Ok(Self::default());
+        };
+        Self::parse(&raw, path.parent().unwrap_or(Path::new("")))
+            .with_context(|| format!("invalid config in {}", path.display()))
+    }
+
+    /// Parse a config with inputs relative to `dir`
+    pub fn parse(raw: &str, dir: &Path) -> Result<Self> {
+        let mut config: Self = toml::from_str(raw)?;
+        for (key, day) in &mut config.days {
+            let valid = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u8>().ok())
+                .is_some_and(|d| day_key(d) == *key);
+            if !valid {
+                bail!("'{key}' is not a day");
+            }
+            if let Some(input) = &mut day.input {
+                *input = dir.join(&*input);
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn get(&self, day: u8) -> Option<&DayConfig> {
+        self.days.get(&day_key(day))
+    }
+
+    /// The input to solve for `day`
+    pub fn input(&self, day: u8) -> PathBuf {
+        self.get(day)
+            .and_then(|d| d.input.clone())
+            .unwrap_or_else(|| input::default_path(day))
+    }
+
+    /// Give `puzzle` the parameters set for its day
+    pub fn configure(&self, puzzle: &mut dyn Puzzle) -> Result<()> {
+        match self.get(puzzle.day()) {
+            Some(day) => puzzle.configure(day.params.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// Fail unless `answer` is the one expected for `part` of `day`, if any is
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Result<()> {
+        match self.get(day).and_then(|d| d.expected(part)) {
+            Some(expected) if expected != answer => {
+                bail!("day {day} part {part} gave {answer}, the config expects {expected}")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aoc.toml");
+        fs::write(
+            &path,
+            "[day2]\ninput = \"inputs/day2.txt\"\npart1 = \"8\"\n\n[day2.params]\nbag = { red = 1, green = 2, blue = 3 }\n\n[day11]\npart2 = \"82000210\"\n",
+        )
+        .unwrap();
+        let config = Config::load(&path).unwrap();
+
+        assert_eq!(config.input(2), dir.path().join("inputs/day2.txt"));
+        assert_eq!(config.input(11), input::default_path(11));
+        assert!(config.check(2, Part::One, "8").is_ok());
+        assert!(config.check(2, Part::One, "9").is_err());
+        assert!(config.check(2, Part::Two, "9").is_ok());
+        assert!(config.check(11, Part::Two, "82000210").is_ok());
+        assert_eq!(
+            config.get(2).unwrap().params["bag"]["green"].as_integer(),
+            Some(2)
+        );
+        assert!(config.get(3).is_none());
+
+        // a config that isn't there is all defaults
+        let config = Config::load(&dir.path().join("missing.toml")).unwrap();
+        assert_eq!(config.input(2), input::default_path(2));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for raw in [
+            "[day2]\nanswer = \"8\"\n",
+            "[dayfive]\npart1 = \"35\"\n",
+            "[day05]\npart1 = \"35\"\n",
+            "title = \"2023\"\n",
+        ] {
+            assert!(Config::parse(raw, Path::new("")).is_err(), "{raw}");
+        }
+    }
+}
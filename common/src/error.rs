@@ -0,0 +1,127 @@
+//! The error every day reports malformed or unsolvable input with
+
+use grid::GridError;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Input that couldn't be parsed. `line` and `column` start at 1 and point at where parsing
+    /// stopped.
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// Input that parsed but can't be solved
+    Invalid { day: u8, message: String },
+    /// An answer too big for the integers it's computed in, caught by the `checked` feature
+    Overflow { day: u8, operation: String },
+}
+
+impl Error {
+    /// Parse failure at `rest`, the unparsed tail of `input`
+    pub fn parse_at(day: u8, input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let offset = input.len().saturating_sub(rest.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self::Parse {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    /// Parse failure at a 0-based (row, col) of the input
+    pub fn parse_at_position(day: u8, row: usize, col: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            day,
+            line: row + 1,
+            column: col + 1,
+            message: message.into(),
+        }
+    }
+
+    /// The input of a grid shaped day wasn't a valid grid
+    pub fn grid(day: u8, e: GridError) -> Self {
+        match e {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => Self::parse_at_position(
+                day,
+                row,
+                expected.min(found),
+                format!("expected {expected} columns, found {found}"),
+            ),
+            GridError::InvalidCell { row, col, value } => {
+                Self::parse_at_position(day, row, col, format!("invalid cell '{value}'"))
+            }
+        }
+    }
+
+    pub fn invalid(day: u8, message: impl Into<String>) -> Self {
+        Self::Invalid {
+            day,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse {
+                day,
+                line,
+                column,
+                message,
+            } => write!(f, "day {day} input line {line}, column {column}: {message}"),
+            Self::Invalid { day, message } => write!(f, "day {day}: {message}"),
+            Self::Overflow { day, operation } => write!(f, "day {day}: overflow in {operation}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_at() {
+        let input = "abc\ndef\nghi";
+        assert_eq!(
+            Error::parse_at(1, input, &input[5..], "bad"),
+            Error::Parse {
+                day: 1,
+                line: 2,
+                column: 2,
+                message: "bad".to_string()
+            }
+        );
+        assert_eq!(
+            Error::parse_at(1, input, "", "bad").to_string(),
+            "day 1 input line 3, column 4: bad"
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let e = Error::grid(
+            3,
+            GridError::Ragged {
+                row: 4,
+                expected: 10,
+                found: 7,
+            },
+        );
+        assert_eq!(
+            e.to_string(),
+            "day 3 input line 5, column 8: expected 10 columns, found 7"
+        );
+    }
+}
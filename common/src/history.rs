@@ -0,0 +1,176 @@
+//! Every answer submitted to the site and what it said about it, stored per day and keyed by a
+//! hash of the input like the answers manifest
+
+use crate::workspace::{self, day_key};
+use crate::Part;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// What the site said about a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way
+    Wrong,
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Submissions for one input
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Attempts {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part1: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part2: Vec<Submission>,
+}
+
+impl Attempts {
+    pub fn get(&self, part: Part) -> &[Submission] {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+
+    fn get_mut(&mut self, part: Part) -> &mut Vec<Submission> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// The submission history: `[dayN.<input hash>]` tables holding the `part1`/`part2` submissions
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History {
+    days: BTreeMap<String, BTreeMap<String, Attempts>>,
+}
+
+/// The history is personal, so it's kept out of the repo with the cached inputs
+pub fn default_path() -> PathBuf {
+    workspace::root().join(".cache").join("submissions.toml")
+}
+
+impl History {
+    /// Load a history; a history that doesn't exist yet is empty
+    pub fn load(path: &Path) -> Result<Self> {
+        workspace::load_toml(path, "history")
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        workspace::save_toml(path, self)
+    }
+
+    /// Submissions so far for `part` of `day` with the input hashing to `hash`
+    pub fn get(&self, day: u8, hash: &str, part: Part) -> &[Submission] {
+        self.days
+            .get(&day_key(day))
+            .and_then(|inputs| inputs.get(hash))
+            .map_or(&[], |attempts| attempts.get(part))
+    }
+
+    pub fn insert(&mut self, day: u8, hash: &str, part: Part, submission: Submission) {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(hash.to_string())
+            .or_default()
+            .get_mut(part)
+            .push(submission);
+    }
+
+    /// The verdict `answer` is already known to get: the one it got when it was submitted
+    /// before, or too high/low if it's past an answer that was
+    pub fn known(&self, day: u8, hash: &str, part: Part, answer: &str) -> Option<Verdict> {
+        let submissions = self.get(day, hash, part);
+        if let Some(s) = submissions.iter().find(|s| s.answer == answer) {
+            return Some(s.verdict);
+        }
+
+        let answer: i128 = answer.parse().ok()?;
+        submissions.iter().find_map(|s| {
+            let bound: i128 = s.answer.parse().ok()?;
+            match s.verdict {
+                Verdict::TooHigh if answer >= bound => Some(Verdict::TooHigh),
+                Verdict::TooLow if answer <= bound => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut history = History::default();
+        history.insert(5, "abc", Part::One, submission("40", Verdict::TooHigh));
+        history.insert(5, "abc", Part::One, submission("35", Verdict::Correct));
+        history.insert(7, "def", Part::Two, submission("6440", Verdict::Wrong));
+
+        let raw = toml::to_string(&history).unwrap();
+        let history: History = toml::from_str(&raw).unwrap();
+
+        assert_eq!(
+            history.get(5, "abc", Part::One),
+            [
+                submission("40", Verdict::TooHigh),
+                submission("35", Verdict::Correct)
+            ]
+        );
+        assert!(history.get(5, "abc", Part::Two).is_empty());
+        assert_eq!(
+            history.get(7, "def", Part::Two),
+            [submission("6440", Verdict::Wrong)]
+        );
+    }
+
+    #[test]
+    fn test_known() {
+        let mut history = History::default();
+        history.insert(1, "abc", Part::One, submission("100", Verdict::TooHigh));
+        history.insert(1, "abc", Part::One, submission("20", Verdict::TooLow));
+        history.insert(1, "abc", Part::One, submission("50", Verdict::Wrong));
+
+        let known = |answer| history.known(1, "abc", Part::One, answer);
+        assert_eq!(known("50"), Some(Verdict::Wrong));
+        assert_eq!(known("100"), Some(Verdict::TooHigh));
+        assert_eq!(known("150"), Some(Verdict::TooHigh));
+        assert_eq!(known("-3"), Some(Verdict::TooLow));
+        assert_eq!(known("60"), None);
+        assert_eq!(known("sixty"), None);
+        assert_eq!(history.known(1, "abc", Part::Two, "150"), None);
+    }
+}
@@ -0,0 +1,36 @@
+//! Locating and reading puzzle input
+
+use crate::workspace::{self, day_key};
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Every day keeps its puzzle input next to its Cargo.toml
+pub fn default_path(day: u8) -> PathBuf {
+    workspace::root().join(day_key(day)).join("input.txt")
+}
+
+/// Read puzzle input from `path`, or from stdin when `path` is `-`
+pub fn read(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("unable to read input from stdin")?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).with_context(|| format!("unable to read {}", path.display()))
+    }
+}
+
+/// Short, stable fingerprint of an input so answers can be matched to it without storing the
+/// input itself
+pub fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .take(8)
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
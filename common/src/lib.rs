@@ -0,0 +1,199 @@
+//! Pieces shared by every day's solution and the `aoc` runner
+
+use anyhow::{anyhow, bail, Result};
+use bench::Alternative;
+pub use error::Error;
+use output::Solved;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use repl::Command;
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use std::time::Instant;
+
+pub mod answers;
+pub mod arith;
+pub mod bench;
+pub mod cli;
+pub mod config;
+pub mod error;
+pub mod history;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod repl;
+pub mod workspace;
+
+/// Which half of a day's puzzle to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in the order they should be run
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("invalid part '{s}': expected 1 or 2")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A single day's puzzle. The input is parsed once and then handed to each part.
+pub trait Solution {
+    /// Day of December this solves
+    const DAY: u8;
+
+    /// Parsed form of the puzzle input shared by both parts
+    type Input;
+
+    /// Parse raw puzzle input
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    /// Solve part 1 of the puzzle
+    fn part1(&self, _input: &Self::Input) -> Result<String> {
+        Err(anyhow!("day {} part 1 is not implemented", Self::DAY))
+    }
+
+    /// Solve part 2 of the puzzle
+    fn part2(&self, _input: &Self::Input) -> Result<String> {
+        Err(anyhow!("day {} part 2 is not implemented", Self::DAY))
+    }
+
+    /// Other implementations of either part to compare against when benchmarking
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        Vec::new()
+    }
+
+    /// Random, structurally valid puzzle input. `size` scales it (lines, grid width, ...) as
+    /// documented by each day. None if the day has no generator.
+    fn generate(&self, _rng: &mut StdRng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// Set the puzzle's parameters from the `[dayN.params]` table of the project config. Days
+    /// without parameters take none.
+    fn configure(&mut self, params: config::Table) -> Result<()> {
+        match params.keys().next() {
+            Some(key) => bail!("day {} has no parameter '{key}'", Self::DAY),
+            None => Ok(()),
+        }
+    }
+
+    /// Queries of the parsed input offered by `aoc repl`, on top of solving either part
+    fn commands(&self) -> Vec<Command<Self>> {
+        Vec::new()
+    }
+}
+
+/// Object safe view of a [`Solution`] so the runner can keep every day in one list
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    /// Parse `input` and solve the requested part
+    fn solve(&self, input: &str, part: Part) -> Result<String>;
+
+    /// [`Puzzle::solve`], timing the parsing and the solving separately
+    fn solve_timed(&self, input: &str, part: Part) -> Result<Solved>;
+
+    /// Time parsing and every implementation of `parts` over `iterations` runs
+    fn bench(&self, input: &str, parts: &[Part], iterations: usize) -> Result<bench::Report>;
+
+    /// Random puzzle input of roughly `size`, the same for the same `seed`
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+
+    /// Set the puzzle's parameters from the project config
+    fn configure(&mut self, params: config::Table) -> Result<()>;
+
+    /// Parse `input` and answer the commands read from `lines` on `out`
+    fn repl(&self, input: &str, lines: &mut dyn BufRead, out: &mut dyn Write) -> Result<()>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(&self, input: &str, part: Part) -> Result<String> {
+        let parsed = self.parse(input)?;
+        match part {
+            Part::One => self.part1(&parsed),
+            Part::Two => self.part2(&parsed),
+        }
+    }
+
+    fn solve_timed(&self, input: &str, part: Part) -> Result<Solved> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let parse_time = start.elapsed();
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => self.part1(&parsed),
+            Part::Two => self.part2(&parsed),
+        }?;
+        Ok(Solved {
+            day: S::DAY,
+            part,
+            answer,
+            input_hash: input::hash(input),
+            parse_time,
+            solve_time: start.elapsed(),
+        })
+    }
+
+    fn bench(&self, input: &str, parts: &[Part], iterations: usize) -> Result<bench::Report> {
+        bench::run(self, input, parts, iterations)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        Solution::generate(self, &mut StdRng::seed_from_u64(seed), size)
+    }
+
+    fn configure(&mut self, params: config::Table) -> Result<()> {
+        Solution::configure(self, params)
+    }
+
+    fn repl(&self, input: &str, lines: &mut dyn BufRead, out: &mut dyn Write) -> Result<()> {
+        repl::run(self, input, lines, out)
+    }
+}
+
+/// Assert that `puzzle` gives the answer of each `(input, part, answer)` example, for the days'
+/// tests
+pub fn assert_examples(puzzle: &dyn Puzzle, examples: &[(&str, Part, &str)]) {
+    for (i, &(input, part, expected)) in examples.iter().enumerate() {
+        match puzzle.solve(input, part) {
+            Ok(answer) => assert_eq!(
+                answer,
+                expected,
+                "day {} example {} part {part}",
+                puzzle.day(),
+                i + 1
+            ),
+            Err(e) => panic!(
+                "day {} example {} part {part} failed: {e:#}",
+                puzzle.day(),
+                i + 1
+            ),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! How answers are printed: a `Day N part P: answer` line each, or a JSON object each for scripts
+
+use crate::Part;
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use std::time::Duration;
+
+/// How answers are printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `Day N part P: answer`
+    #[default]
+    Text,
+    /// One JSON object per line with the day, part, answer, input hash and timings
+    Json,
+}
+
+/// A solved part of a day and how long it took
+#[derive(Debug, Clone, Serialize)]
+pub struct Solved {
+    pub day: u8,
+    #[serde(serialize_with = "part_number")]
+    pub part: Part,
+    pub answer: String,
+    /// [`crate::input::hash`] of the input
+    pub input_hash: String,
+    #[serde(rename = "parse_time_ns", serialize_with = "nanos")]
+    pub parse_time: Duration,
+    #[serde(rename = "solve_time_ns", serialize_with = "nanos")]
+    pub solve_time: Duration,
+}
+
+fn part_number<S: Serializer>(part: &Part, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u8(match part {
+        Part::One => 1,
+        Part::Two => 2,
+    })
+}
+
+fn nanos<S: Serializer>(duration: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+}
+
+impl Solved {
+    /// The line to print for this part in `format`
+    pub fn line(&self, format: Format) -> Result<String> {
+        Ok(match format {
+            Format::Text => format!("Day {} part {}: {}", self.day, self.part, self.answer),
+            Format::Json => serde_json::to_string(self)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    #[test]
+    fn test_line() {
+        let solved = Solved {
+            day: 9,
+            part: Part::Two,
+            answer: "864".to_string(),
+            input_hash: input::hash("0 3 6 9 12 15\n"),
+            parse_time: Duration::from_micros(250),
+            solve_time: Duration::from_nanos(1_500),
+        };
+        assert_eq!(solved.line(Format::Text).unwrap(), "Day 9 part 2: 864");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&solved.line(Format::Json).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "day": 9,
+                "part": 2,
+                "answer": "864",
+                "input_hash": solved.input_hash,
+                "parse_time_ns": 250_000,
+                "solve_time_ns": 1_500,
+            })
+        );
+    }
+}
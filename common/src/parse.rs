@@ -0,0 +1,183 @@
+//! nom parsers for the input shapes that keep coming up: whitespace separated numbers, labelled
+//! lines and sections, blank line separated blocks, key/value lines and character grids.
+//!
+//! Everything is generic over the nom error type so days with their own error can still use them.
+
+use crate::Error;
+use grid::Grid;
+use nom::{
+    bytes::complete::{is_not, tag, take_until},
+    character::complete::{digit1, line_ending, multispace0, space0, space1},
+    combinator::{eof, opt, recognize},
+    error::{ErrorKind, ParseError},
+    multi::{separated_list0, separated_list1},
+    sequence::{delimited, pair, preceded, terminated, tuple},
+    IResult, Parser,
+};
+use std::str::FromStr;
+
+/// A single, optionally negative, integer
+pub fn number<'a, T: FromStr, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, T, E> {
+    let (remaining, digits) = recognize(pair(opt(tag("-")), digit1))(input)?;
+    match digits.parse() {
+        Ok(n) => Ok((remaining, n)),
+        Err(_) => Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Digit))),
+    }
+}
+
+/// Numbers on one line separated by spaces, e.g. `79 14  55 13`. Leading spaces are skipped.
+pub fn numbers<'a, T: FromStr, E: ParseError<&'a str>>(
+    input: &'a str,
+) -> IResult<&'a str, Vec<T>, E> {
+    preceded(space0, separated_list1(space1, number))(input)
+}
+
+/// `<label>: <value>` on one line, e.g. `labelled("Time", numbers)` for `Time:  7  15  30`
+pub fn labelled<'a, O, E: ParseError<&'a str>>(
+    label: &'static str,
+    value: impl Parser<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O, E> {
+    preceded(tuple((tag(label), tag(":"), space0)), value)
+}
+
+/// `<key> <sep> <value>` on one line with optional spaces around the separator, e.g.
+/// `key_value(alphanumeric1, "=", dest)` for `AAA = (BBB, CCC)`
+pub fn key_value<'a, K, V, E: ParseError<&'a str>>(
+    key: impl Parser<&'a str, K, E>,
+    sep: &'static str,
+    value: impl Parser<&'a str, V, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V), E> {
+    tuple((key, preceded(delimited(space0, tag(sep), space0), value)))
+}
+
+/// Runs `line` on consecutive lines, stopping at the first one it can't parse
+pub fn lines<'a, O, E: ParseError<&'a str>>(
+    line: impl Parser<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>, E> {
+    separated_list0(line_ending, line)
+}
+
+/// A header line ending in `:` followed by `body` on the lines after it, e.g. the
+/// `seed-to-soil map:` sections of day 5. Returns the header without its colon.
+pub fn section<'a, O, E: ParseError<&'a str>>(
+    body: impl Parser<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, O), E> {
+    pair(
+        terminated(take_until(":"), pair(tag(":"), line_ending)),
+        body,
+    )
+}
+
+/// Runs `block` on chunks of input separated by blank lines
+pub fn blocks<'a, O, E: ParseError<&'a str>>(
+    block: impl Parser<&'a str, O, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>, E> {
+    separated_list1(pair(line_ending, line_ending), block)
+}
+
+/// Every line as a row of characters. Use [`Grid::parse_with`] directly to convert the cells.
+pub fn char_grid<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, Grid<char>, E> {
+    let (remaining, rows) = separated_list1(
+        line_ending,
+        is_not("\r\n").map(|l: &str| l.chars().collect::<Vec<_>>()),
+    )(input)?;
+    match Grid::from_rows(rows) {
+        Ok(grid) => Ok((remaining, grid)),
+        Err(_) => Err(nom::Err::Failure(E::from_error_kind(
+            input,
+            ErrorKind::Verify,
+        ))),
+    }
+}
+
+/// Parse all of `input` with `parser`, allowing trailing whitespace. A failure points at where
+/// parsing stopped, with `what` naming the input in the message.
+pub fn all<'a, O>(
+    day: u8,
+    what: &str,
+    input: &'a str,
+    parser: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> Result<O, Error> {
+    match terminated(parser, pair(multispace0, eof))(input) {
+        Ok((_, o)) => Ok(o),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(Error::parse_at(
+            day,
+            input,
+            e.input,
+            format!("unable to parse {what} ({})", e.code.description()),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(Error::parse_at(
+            day,
+            input,
+            "",
+            format!("unable to parse {what} (incomplete)"),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete::alphanumeric1;
+
+    type Res<'a, O> = IResult<&'a str, O, nom::error::Error<&'a str>>;
+
+    #[test]
+    fn test_numbers() {
+        let r: Res<Vec<i64>> = numbers("  79 14  -55 13\n1");
+        assert_eq!(r, Ok(("\n1", vec![79, 14, -55, 13])));
+        let r: Res<Vec<u8>> = numbers("300");
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn test_labelled() {
+        let r: Res<Vec<u32>> = labelled("Time", numbers)("Time:      7  15   30");
+        assert_eq!(r, Ok(("", vec![7, 15, 30])));
+    }
+
+    #[test]
+    fn test_key_value() {
+        let r: Res<(&str, u32)> = key_value(alphanumeric1, "=", number)("AAA = 12");
+        assert_eq!(r, Ok(("", ("AAA", 12))));
+    }
+
+    #[test]
+    fn test_sections_and_blocks() {
+        let input = "a map:\n1 2\n3 4\n\nb map:\n5 6\n";
+        type Section<'a> = (&'a str, Vec<Vec<u32>>);
+        let r: Res<Vec<Section>> = blocks(section(lines(numbers)))(input);
+        assert_eq!(
+            r,
+            Ok((
+                "\n",
+                vec![
+                    ("a map", vec![vec![1, 2], vec![3, 4]]),
+                    ("b map", vec![vec![5, 6]])
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let r: Res<Grid<char>> = char_grid("ab\ncd\n");
+        assert_eq!(r.map(|(_, g)| g[(1, 0)]), Ok('c'));
+        let r: Res<Grid<char>> = char_grid("ab\nc");
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn test_all() {
+        assert_eq!(
+            all(1, "list", "1 2 3\n\n", numbers::<u32, _>),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            all(1, "list", "1 2\n3 x", lines(numbers::<u32, _>))
+                .unwrap_err()
+                .to_string(),
+            "day 1 input line 2, column 3: unable to parse list (End of file)"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! An interactive session over one parsed input, for querying the intermediate state of a day with
+//! commands the day registers
+
+use crate::Solution;
+use anyhow::{bail, Result};
+use std::io::{BufRead, Write};
+
+/// Signature of a day's command: the parsed input and the words after the command's name
+pub type CommandFn<S> = fn(&S, &<S as Solution>::Input, &[&str]) -> Result<String>;
+
+/// A query a day offers in the session, e.g. what a seed converts to
+pub struct Command<S: Solution + ?Sized> {
+    pub name: &'static str,
+    /// The arguments it takes as shown in `help`, one word each, e.g. `<loc> <L|R>`
+    pub args: &'static str,
+    pub help: &'static str,
+    pub run: CommandFn<S>,
+}
+
+impl<S: Solution + ?Sized> Command<S> {
+    fn usage(&self) -> String {
+        format!("{} {}", self.name, self.args)
+            .trim_end()
+            .to_string()
+    }
+}
+
+/// Commands every day has
+const BUILTIN: [(&str, &str); 4] = [
+    ("part1", "solve part 1"),
+    ("part2", "solve part 2"),
+    ("help", "list the commands"),
+    ("quit", "end the session"),
+];
+
+fn help<S: Solution>(commands: &[Command<S>]) -> String {
+    let usages: Vec<(String, &str)> = commands
+        .iter()
+        .map(|c| (c.usage(), c.help))
+        .chain(BUILTIN.iter().map(|&(name, help)| (name.to_string(), help)))
+        .collect();
+    let width = usages.iter().map(|(u, _)| u.len()).max().unwrap_or(0);
+    usages
+        .iter()
+        .map(|(usage, help)| format!("  {usage:width$}  {help}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// What `line` gives, or None to end the session
+fn eval<S: Solution>(
+    solution: &S,
+    parsed: &S::Input,
+    commands: &[Command<S>],
+    line: &str,
+) -> Result<Option<String>> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((&name, args)) = words.split_first() else {
+        return Ok(Some(String::new()));
+    };
+    let output = match name {
+        "part1" => solution.part1(parsed)?,
+        "part2" => solution.part2(parsed)?,
+        "help" => help(commands),
+        "quit" | "exit" => return Ok(None),
+        _ => {
+            let Some(command) = commands.iter().find(|c| c.name == name) else {
+                bail!("unknown command '{name}', try help");
+            };
+            if args.len() != command.args.split_whitespace().count() {
+                bail!("usage: {}", command.usage());
+            }
+            (command.run)(solution, parsed, args)?
+        }
+    };
+    Ok(Some(output))
+}
+
+/// Parse `input` once, then answer each command read from `lines` on `out` until `quit` or the end
+/// of `lines`. A failing command is reported and the session carries on.
+pub fn run<S: Solution>(
+    solution: &S,
+    input: &str,
+    lines: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> Result<()> {
+    let parsed = solution.parse(input)?;
+    let commands = solution.commands();
+    writeln!(
+        out,
+        "Day {} input loaded, `help` lists the commands",
+        S::DAY
+    )?;
+
+    let mut line = String::new();
+    loop {
+        write!(out, "day{}> ", S::DAY)?;
+        out.flush()?;
+        line.clear();
+        if lines.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            return Ok(());
+        }
+        match eval(solution, &parsed, &commands, &line) {
+            Ok(None) => return Ok(()),
+            Ok(Some(output)) if output.is_empty() => {}
+            Ok(Some(output)) => writeln!(out, "{output}")?,
+            Err(e) => writeln!(out, "error: {e:#}")?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Numbers, one per line
+    struct Numbers;
+
+    impl Solution for Numbers {
+        const DAY: u8 = 1;
+
+        type Input = Vec<i64>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(&self, numbers: &Self::Input) -> Result<String> {
+            Ok(numbers.iter().sum::<i64>().to_string())
+        }
+
+        fn commands(&self) -> Vec<Command<Self>> {
+            vec![Command {
+                name: "nth",
+                args: "<n>",
+                help: "the nth number",
+                run: |_, numbers, args| {
+                    let n: usize = args[0].parse()?;
+                    match numbers.get(n) {
+                        Some(number) => Ok(number.to_string()),
+                        None => bail!("there are only {} numbers", numbers.len()),
+                    }
+                },
+            }]
+        }
+    }
+
+    fn session(commands: &str) -> String {
+        let mut out = Vec::new();
+        run(&Numbers, "3\n4\n5\n", &mut commands.as_bytes(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_session() {
+        let out = session("nth 1\n\npart1\nnth 7\nnth\nfrobnicate\npart2\nquit\nnth 0\n");
+        let replies: Vec<&str> = out.lines().skip(1).collect();
+        assert_eq!(
+            replies,
+            [
+                "day1> 4",
+                "day1> day1> 12",
+                "day1> error: there are only 3 numbers",
+                "day1> error: usage: nth <n>",
+                "day1> error: unknown command 'frobnicate', try help",
+                "day1> error: day 1 part 2 is not implemented",
+                "day1> ",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_help() {
+        let out = session("help");
+        assert!(out.contains("  nth <n>  the nth number\n"), "{out}");
+        assert!(out.contains("  part1    solve part 1\n"), "{out}");
+    }
+}
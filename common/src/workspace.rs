@@ -0,0 +1,74 @@
+//! Where the workspace keeps its files: the root everything is found from, the `dayN` name each
+//! day goes by and the TOML files (config, answers, submissions) kept per day
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The workspace these crates were built in
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// The name of `day`: its crate and its table in the TOML files
+pub fn day_key(day: u8) -> String {
+    format!("day{day}")
+}
+
+/// The contents of `path`, or None if it doesn't exist yet
+pub fn read_optional(path: &Path) -> Result<Option<String>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    fs::read_to_string(path)
+        .map(Some)
+        .with_context(|| format!("unable to read {}", path.display()))
+}
+
+/// Load the TOML file at `path`, with `what` naming its contents in errors. A file that doesn't
+/// exist yet is the default.
+pub fn load_toml<T: DeserializeOwned + Default>(path: &Path, what: &str) -> Result<T> {
+    match read_optional(path)? {
+        Some(raw) => {
+            toml::from_str(&raw).with_context(|| format!("invalid {what} in {}", path.display()))
+        }
+        None => Ok(T::default()),
+    }
+}
+
+/// Save `value` as the TOML file at `path`, creating its directory if need be
+pub fn save_toml<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("unable to create {}", dir.display()))?;
+    }
+    fs::write(path, toml::to_string(value)?)
+        .with_context(|| format!("unable to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_toml_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("values.toml");
+
+        let values: BTreeMap<String, u32> = load_toml(&path, "values").unwrap();
+        assert!(values.is_empty());
+
+        let values = BTreeMap::from([(day_key(5), 35)]);
+        save_toml(&path, &values).unwrap();
+        assert_eq!(
+            load_toml::<BTreeMap<String, u32>>(&path, "values").unwrap(),
+            values
+        );
+
+        fs::write(&path, "day5 = \"x\"\n").unwrap();
+        let e = load_toml::<BTreeMap<String, u32>>(&path, "values").unwrap_err();
+        assert!(format!("{e:#}").starts_with("invalid values in "), "{e:#}");
+    }
+}
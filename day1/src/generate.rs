@@ -0,0 +1,30 @@
+//! Random calibration documents
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const SPELLED: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of lowercase letters, spelled out digits and at least one real digit
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.gen_range(1..8) {
+            match rng.gen_range(0..3) {
+                0 => line.push(rng.gen_range(b'a'..=b'z') as char),
+                1 => line.push_str(SPELLED.choose(rng).unwrap()),
+                _ => line.push(rng.gen_range(b'1'..=b'9') as char),
+            }
+        }
+        if !line.chars().any(|c| c.is_ascii_digit()) {
+            line.push(rng.gen_range(b'1'..=b'9') as char);
+        }
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,102 @@
+use anyhow::Result;
+use common::{arith, Error, Solution};
+use rand::rngs::StdRng;
+
+mod generate;
+
+/// Day 1: Trebuchet?!
+pub struct Day1;
+
+/// The digits of `line`, counting spelled out ones. Spelled digits can share letters
+/// (`eightwo`), so each is kept around its digit to leave the neighbours intact.
+pub fn parse_line(line: &str) -> String {
+    line.replace("one", "one1one")
+        .replace("two", "two2two")
+        .replace("three", "three3three")
+        .replace("four", "four4four")
+        .replace("five", "five5five")
+        .replace("six", "six6six")
+        .replace("seven", "seven7seven")
+        .replace("eight", "eight8eight")
+        .replace("nine", "nine9nine")
+        .chars()
+        .filter(|&c| c.is_ascii_digit())
+        .collect::<String>()
+}
+
+/// Combine the first and last digit of line `row` into its calibration value
+pub fn calibration_value(row: usize, digits: &str) -> Result<i64, Error> {
+    let first = digits.chars().next().and_then(|c| c.to_digit(10));
+    let last = digits.chars().next_back().and_then(|c| c.to_digit(10));
+    match (first, last) {
+        (Some(first), Some(last)) => Ok((first * 10 + last).into()),
+        _ => Err(Error::parse_at_position(
+            Day1::DAY,
+            row,
+            0,
+            "line has no digits",
+        )),
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<String> {
+        let mut total = 0;
+        for (row, line) in input.iter().enumerate() {
+            let digits = line
+                .chars()
+                .filter(|&c| c.is_ascii_digit())
+                .collect::<String>();
+            total = arith::add(
+                Self::DAY,
+                "calibration total",
+                total,
+                calibration_value(row, &digits)?,
+            )?;
+        }
+        Ok(total.to_string())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<String> {
+        let mut total = 0;
+        for (row, line) in input.iter().enumerate() {
+            let digits = parse_line(line);
+            total = arith::add(
+                Self::DAY,
+                "calibration total",
+                total,
+                calibration_value(row, &digits)?,
+            )?;
+        }
+        Ok(total.to_string())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{assert_examples, Part};
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day1,
+            &[
+                (include_str!("../test1.txt"), Part::One, "142"),
+                (include_str!("../test2.txt"), Part::Two, "281"),
+            ],
+        );
+    }
+}
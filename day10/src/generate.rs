@@ -0,0 +1,82 @@
+//! Random pipe mazes
+
+use crate::Graph;
+use grid::{Coordinate, Direction, Grid};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+/// A `size + 1` square maze whose loop is the outline of a random blob of `size` by `size`
+/// cells, with junk pipes everywhere else.
+///
+/// The blob is one interval of cells per column, each overlapping the one before it. Its outline
+/// then never touches itself, so it is a single loop running along the corners of the cells.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let cells = blob(rng, size);
+    let inside = |row: i64, col: i64| row >= 0 && col >= 0 && cells[row as usize][col as usize];
+
+    // the tile at (row, col) is the top left corner of the cell at (row, col)
+    let mut tiles = Grid::from_rows(vec![vec!['.'; size + 1]; size + 1]).unwrap();
+    let mut main_loop = HashSet::new();
+    for row in 0..=size {
+        for col in 0..=size {
+            let (r, c) = (row as i64, col as i64);
+            let dirs: Vec<Direction> = [
+                (Direction::North, inside(r - 1, c - 1) != inside(r - 1, c)),
+                (Direction::East, inside(r - 1, c) != inside(r, c)),
+                (Direction::South, inside(r, c - 1) != inside(r, c)),
+                (Direction::West, inside(r - 1, c - 1) != inside(r, c - 1)),
+            ]
+            .into_iter()
+            .filter_map(|(dir, edge)| edge.then_some(dir))
+            .collect();
+            tiles[(row, col)] = match dirs[..] {
+                [a, b] => {
+                    main_loop.insert(Coordinate::from_usize(row, col));
+                    Graph::pipe(a, b).unwrap()
+                }
+                _ if rng.gen_bool(0.5) => *PIPES.choose(rng).unwrap(),
+                _ => '.',
+            };
+        }
+    }
+
+    let mut starts: Vec<Coordinate> = main_loop.iter().copied().collect();
+    starts.sort();
+    let start = *starts.choose(rng).unwrap();
+    tiles[start.to_usize().unwrap()] = 'S';
+    // nothing but the loop may connect to S
+    for pos in start.neighbors4() {
+        let connects = tiles
+            .at(pos)
+            .and_then(|&sym| Graph::compute_neighbors(sym))
+            .is_some_and(|(a, b)| pos.step(a) == start || pos.step(b) == start);
+        if connects && !main_loop.contains(&pos) {
+            tiles[pos.to_usize().unwrap()] = '.';
+        }
+    }
+
+    tiles.to_string()
+}
+
+/// Column intervals of cells, each overlapping the previous column's
+fn blob(rng: &mut StdRng, size: usize) -> Vec<Vec<bool>> {
+    let mut cells = vec![vec![false; size + 1]; size + 1];
+    let first = rng.gen_range(0..size);
+    let last = rng.gen_range(first..size);
+    let (mut lo, mut hi) = (0, size - 1);
+    for col in first..=last {
+        // a new interval somewhere covering at least one row of the previous one
+        let shared = rng.gen_range(lo..=hi);
+        lo = rng.gen_range(0..=shared);
+        hi = rng.gen_range(shared..size);
+        for row in cells.iter_mut().take(hi + 1).skip(lo) {
+            row[col] = true;
+        }
+    }
+    cells
+}
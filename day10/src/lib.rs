@@ -0,0 +1,336 @@
+use anyhow::{anyhow, Context, Result};
+use common::repl::Command;
+use common::{Error, Solution};
+use grid::{Coordinate, Direction, Grid};
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+
+mod generate;
+
+/// Day 10: Pipe Maze
+pub struct Day10;
+
+/// A tile of the maze and the two positions its pipe connects (itself twice if it isn't a pipe)
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub sym: char,
+    pub neighbors: (Coordinate, Coordinate),
+}
+
+impl std::fmt::Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.sym)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Graph {
+    start_coord: Option<Coordinate>,
+    map: Grid<Node>,
+    main_loop: Option<HashSet<Coordinate>>,
+}
+
+impl std::fmt::Display for Graph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.map)
+    }
+}
+
+impl Graph {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut start_coord = None;
+        let map = Grid::parse(input)
+            .map_err(|e| Error::grid(Day10::DAY, e))?
+            .map(|pos, &v| {
+                let pos = Coordinate::from(pos);
+                if v == 'S' {
+                    start_coord = Some(pos);
+                }
+                // anything that isn't a pipe only connects to itself
+                let neighbors = match Graph::compute_neighbors(v) {
+                    Some((a, b)) => (pos.step(a), pos.step(b)),
+                    None => (pos, pos),
+                };
+                Node { sym: v, neighbors }
+            });
+
+        Ok(Self {
+            start_coord,
+            map,
+            main_loop: None,
+        })
+    }
+
+    /// The two directions a pipe connects, or None if `sym` isn't a pipe
+    pub fn compute_neighbors(sym: char) -> Option<(Direction, Direction)> {
+        use Direction::*;
+        match sym {
+            '|' => Some((South, North)),
+            '-' => Some((East, West)),
+            'L' => Some((North, East)),
+            'J' => Some((North, West)),
+            '7' => Some((South, West)),
+            'F' => Some((South, East)),
+            _ => None,
+        }
+    }
+
+    /// The pipe connecting `a` and `b`, or None if no pipe does
+    pub fn pipe(a: Direction, b: Direction) -> Option<char> {
+        ['|', '-', 'L', 'J', '7', 'F'].into_iter().find(|&sym| {
+            Graph::compute_neighbors(sym).is_some_and(|(x, y)| (x, y) == (a, b) || (y, x) == (a, b))
+        })
+    }
+
+    pub fn find_main_loop(&mut self) -> Result<(), Error> {
+        // find start's orientation
+        let mut neighs = Vec::new();
+        let mut dirs = Vec::new();
+        let s = self
+            .start_coord
+            .ok_or_else(|| Error::invalid(Day10::DAY, "no start 'S' in the map"))?;
+        // checkout each neighbor
+        for dir in Direction::ORTHOGONAL {
+            let c = s.step(dir);
+            // get c's neighbors
+            let candidate = match self.map.at(c) {
+                None => {
+                    // not a possible neighbor of start
+                    continue;
+                }
+                Some(c) => c,
+            };
+
+            if candidate.neighbors.0 == s || candidate.neighbors.1 == s {
+                neighs.push(c);
+                dirs.push(dir);
+            }
+        }
+
+        if neighs.len() != 2 {
+            return Err(Error::invalid(
+                Day10::DAY,
+                format!("start connects to {} pipes, expected 2", neighs.len()),
+            ));
+        }
+        // set start's neighbors, and make it the pipe it stands for so ray tracing sees which
+        // corner it is
+        if let Some(start) = self.map.at_mut(s) {
+            start.neighbors = (neighs[0], neighs[1]);
+            start.sym = Graph::pipe(dirs[0], dirs[1]).unwrap_or('S');
+        }
+
+        // walk from start until we get back to it, every step must follow a pipe that connects
+        // back to the tile we came from
+        let at = |c: Coordinate| format!("row {}, col {}", c.row, c.col);
+        let mut path = HashSet::new();
+        path.insert(s);
+        let mut last = s;
+        let mut cur = neighs[0];
+
+        while cur != s {
+            let node = self.map.at(cur).ok_or_else(|| {
+                Error::invalid(
+                    Day10::DAY,
+                    format!("the loop leaves the map at {}", at(last)),
+                )
+            })?;
+            if node.neighbors.0 == cur {
+                return Err(Error::invalid(
+                    Day10::DAY,
+                    format!(
+                        "the loop ends at '{}', not a pipe, at {}",
+                        node.sym,
+                        at(cur)
+                    ),
+                ));
+            }
+            // pick cur's neighbor that we aren't coming from
+            let nxt = if node.neighbors.0 == last {
+                node.neighbors.1
+            } else if node.neighbors.1 == last {
+                node.neighbors.0
+            } else {
+                return Err(Error::invalid(
+                    Day10::DAY,
+                    format!(
+                        "'{}' at {} doesn't connect back to {}",
+                        node.sym,
+                        at(cur),
+                        at(last)
+                    ),
+                ));
+            };
+            if !path.insert(cur) {
+                return Err(Error::invalid(
+                    Day10::DAY,
+                    format!(
+                        "the loop crosses itself at {} instead of returning to S",
+                        at(cur)
+                    ),
+                ));
+            }
+
+            last = cur;
+            cur = nxt;
+        }
+
+        self.main_loop = Some(path);
+        Ok(())
+    }
+
+    /// Compute the max distance from the start point (requires find_main_loop)
+    pub fn max_distance_from_start(&self) -> usize {
+        match self.main_loop.as_ref() {
+            None => 0,
+            // walking both ways around the loop, the two walks meet halfway
+            Some(ml) => ml.len() / 2,
+        }
+    }
+
+    pub fn ray_trace(&self) -> usize {
+        // ray trace diagonally so we can detect when we have collinear items (L and 7; right/down
+        // or left/up)
+        let mut inside = 0;
+
+        let main_loop = match self.main_loop.as_ref() {
+            None => {
+                return 0;
+            }
+            Some(ml) => ml,
+        };
+
+        for row in 0..self.map.rows() {
+            for col in 0..self.map.cols() {
+                let mut pos = Coordinate::from_usize(row, col);
+                if main_loop.contains(&pos) {
+                    continue;
+                }
+
+                let mut crosses = 0;
+                while let Some(node) = self.map.at(pos) {
+                    let sym = node.sym;
+                    if main_loop.contains(&pos) && sym != 'L' && sym != '7' {
+                        crosses += 1;
+                    }
+                    pos = pos.step(Direction::SouthEast);
+                }
+
+                if crosses % 2 == 1 {
+                    inside += 1;
+                }
+            }
+        }
+        inside
+    }
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Graph;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let graph = Graph::parse(input)?;
+        log::trace!("\n{graph}");
+        Ok(graph)
+    }
+
+    fn part1(&self, graph: &Self::Input) -> Result<String> {
+        let mut graph = graph.clone();
+
+        graph.find_main_loop()?;
+
+        Ok(graph.max_distance_from_start().to_string())
+    }
+
+    fn part2(&self, graph: &Self::Input) -> Result<String> {
+        let mut graph = graph.clone();
+
+        graph.find_main_loop()?;
+
+        Ok(graph.ray_trace().to_string())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn commands(&self) -> Vec<Command<Self>> {
+        vec![
+            Command {
+                name: "inside",
+                args: "",
+                help: "how many tiles the loop encloses",
+                run: |_, graph, _| Ok(with_main_loop(graph)?.ray_trace().to_string()),
+            },
+            Command {
+                name: "tile",
+                args: "<row> <col>",
+                help: "the tile at a position and whether it's part of the loop",
+                run: |_, graph, args| {
+                    let [row, col] = [args[0], args[1]].map(|n| {
+                        n.parse::<usize>()
+                            .with_context(|| format!("invalid position '{n}'"))
+                    });
+                    let (row, col) = (row?, col?);
+                    let pos = Coordinate::from_usize(row, col);
+                    let graph = with_main_loop(graph)?;
+                    let node = graph
+                        .map
+                        .at(pos)
+                        .ok_or_else(|| anyhow!("row {row}, col {col} is off the map"))?;
+                    let on_loop = graph.main_loop.as_ref().is_some_and(|ml| ml.contains(&pos));
+                    Ok(format!(
+                        "{} {}",
+                        node.sym,
+                        if on_loop {
+                            "on the loop"
+                        } else {
+                            "off the loop"
+                        }
+                    ))
+                },
+            },
+        ]
+    }
+}
+
+/// A copy of `graph` with its main loop found, for the commands
+fn with_main_loop(graph: &Graph) -> Result<Graph> {
+    let mut graph = graph.clone();
+    graph.find_main_loop()?;
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{assert_examples, Part};
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day10,
+            &[
+                (include_str!("../test1.txt"), Part::One, "4"),
+                (include_str!("../test2.txt"), Part::One, "8"),
+                (include_str!("../test3.txt"), Part::Two, "4"),
+                (include_str!("../test4.txt"), Part::Two, "8"),
+                (include_str!("../test5.txt"), Part::Two, "10"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_start_on_a_corner() {
+        // test3.txt with S moved onto each kind of corner the ray tracing has to tell apart
+        let map = include_str!("../test3.txt").replacen('S', "F", 1);
+        for (row, col) in [(1, 9), (7, 1), (5, 4), (7, 9)] {
+            let mut lines: Vec<String> = map.lines().map(String::from).collect();
+            lines[row].replace_range(col..col + 1, "S");
+            let input = lines.join("\n");
+            assert_examples(&Day10, &[(&input, Part::Two, "4")]);
+        }
+    }
+}
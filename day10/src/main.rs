@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    common::cli::run_main(&mut day10::Day10)
+}
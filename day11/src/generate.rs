@@ -0,0 +1,14 @@
+//! Random galaxy images
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// A `size` by `size` image with roughly one galaxy in every ten pixels
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut out = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        out.extend((0..size).map(|_| if rng.gen_bool(0.1) { '#' } else { '.' }));
+        out.push('\n');
+    }
+    out
+}
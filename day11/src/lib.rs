@@ -0,0 +1,259 @@
+use anyhow::{bail, Result};
+use common::bench::Alternative;
+use common::config::{self, Table};
+use common::{arith, Error, Part, Solution};
+use grid::{Coordinate, Grid};
+use rand::rngs::StdRng;
+use rayon::prelude::*;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+mod generate;
+mod slow;
+
+/// How many rows/columns each empty one becomes in part 1
+pub const PART1_EXPANSION: usize = 2;
+
+/// How many rows/columns each empty one becomes in part 2
+pub const PART2_EXPANSION: usize = 1000000;
+
+/// Day 11: Cosmic Expansion
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day11 {
+    /// How many rows/columns each empty one becomes in part 1
+    pub part1_expansion: usize,
+    /// How many rows/columns each empty one becomes in part 2
+    pub part2_expansion: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self {
+            part1_expansion: PART1_EXPANSION,
+            part2_expansion: PART2_EXPANSION,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    map: Grid<char>,
+    row_adds: HashSet<i64>,
+    col_adds: HashSet<i64>,
+    galaxies: Vec<Coordinate>,
+}
+
+impl Graph {
+    pub fn parse(input: &str) -> Result<Self> {
+        let map = Grid::parse(input).map_err(|e| Error::grid(Day11::DAY, e))?;
+
+        // rows and columns without a galaxy need extra ones added after them
+        let mut row_adds = HashSet::<i64>::new();
+        for (row, r) in map.iter_rows().enumerate() {
+            if !r.contains(&'#') {
+                row_adds.insert(row as i64);
+            }
+        }
+        let mut col_adds = HashSet::<i64>::new();
+        for (col, mut c) in map.iter_columns().enumerate() {
+            if !c.any(|&v| v == '#') {
+                col_adds.insert(col as i64);
+            }
+        }
+
+        let galaxies = map
+            .iter()
+            .filter(|(_, &v)| v == '#')
+            .map(|(pos, _)| Coordinate::from(pos))
+            .collect();
+
+        Ok(Self {
+            map,
+            row_adds,
+            col_adds,
+            galaxies,
+        })
+    }
+
+    /// Finds the number of columns from x1 to x2 with expansions enabled
+    pub fn col_dist(&self, x1: i64, x2: i64, expansion: usize) -> Result<usize, Error> {
+        let mut sum = 0;
+        let rng = if x1 > x2 { x2..x1 } else { x1..x2 };
+        for i in rng {
+            // every column gets at least 1 addition
+            sum += 1;
+            // if it's in the col add, add the amount we need to
+            if self.col_adds.contains(&i) {
+                sum = arith::add(Day11::DAY, "distance", sum, expansion)?;
+            }
+        }
+        Ok(sum)
+    }
+
+    /// Finds the number of rows from y1 to y2 with expansions enabled
+    pub fn row_dist(&self, y1: i64, y2: i64, expansion: usize) -> Result<usize, Error> {
+        let mut sum = 0;
+        let rng = if y1 > y2 { y2..y1 } else { y1..y2 };
+        for i in rng {
+            sum += 1;
+            if self.row_adds.contains(&i) {
+                sum = arith::add(Day11::DAY, "distance", sum, expansion)?;
+            }
+        }
+        Ok(sum)
+    }
+
+    // compute the shortest distance between each galaxy, adding `expansion` rows/columns for
+    // every empty one crossed
+    pub fn all_pairs_shortest_distance(&self, expansion: usize) -> Result<u64, Error> {
+        // each galaxy sums the distances to the ones after it on its own thread
+        let sums = self
+            .galaxies
+            .par_iter()
+            .enumerate()
+            .map(|(galaxy, a)| {
+                let mut sum = 0;
+                for (pair, b) in self.galaxies[galaxy + 1..].iter().enumerate() {
+                    let dist = arith::add(
+                        Day11::DAY,
+                        "distance",
+                        self.col_dist(a.col, b.col, expansion)?,
+                        self.row_dist(a.row, b.row, expansion)?,
+                    )?;
+                    sum = arith::add(Day11::DAY, "sum of distances", sum, dist)?;
+                    log::trace!(
+                        "Shortest distance between {} and {} => {}",
+                        galaxy + 1,
+                        galaxy + 1 + pair + 1,
+                        dist,
+                    );
+                }
+                Ok(sum as u64)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        arith::sum(Day11::DAY, "sum of distances", sums)
+    }
+}
+
+impl Day11 {
+    /// Rows/columns added for every empty one when each becomes `factor` of them
+    fn added(factor: usize) -> Result<usize, Error> {
+        factor
+            .checked_sub(1)
+            .ok_or_else(|| Error::invalid(Self::DAY, "rows and columns can't expand to nothing"))
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Graph;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let g = Graph::parse(input)?;
+        log::trace!("{g:?}");
+        Ok(g)
+    }
+
+    fn part1(&self, g: &Self::Input) -> Result<String> {
+        Ok(
+            g.all_pairs_shortest_distance(Self::added(self.part1_expansion)?)?
+                .to_string(),
+        )
+    }
+
+    fn part2(&self, g: &Self::Input) -> Result<String> {
+        Ok(
+            g.all_pairs_shortest_distance(Self::added(self.part2_expansion)?)?
+                .to_string(),
+        )
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        vec![Alternative {
+            name: "expand",
+            part: Part::One,
+            solve: |day, g| {
+                Ok(slow::all_pairs_shortest_distance(&g.map, day.part1_expansion)?.to_string())
+            },
+        }]
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    /// `part1_expansion = 2` and `part2_expansion = 1000000`
+    fn configure(&mut self, params: Table) -> Result<()> {
+        let day: Self = config::params(Self::DAY, params)?;
+        if day.part1_expansion == 0 || day.part2_expansion == 0 {
+            bail!("day {} rows and columns can't expand to nothing", Self::DAY);
+        }
+        *self = day;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::assert_examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day11::default(),
+            &[(include_str!("../test1.txt"), Part::One, "374")],
+        );
+    }
+
+    #[test]
+    fn test_example_expansions() {
+        let input = include_str!("../test1.txt");
+        for (expansion, expected) in [(10, "1030"), (100, "8410")] {
+            let day = Day11 {
+                part2_expansion: expansion,
+                ..Day11::default()
+            };
+            assert_examples(&day, &[(input, Part::Two, expected)]);
+        }
+
+        let day = Day11 {
+            part1_expansion: 0,
+            ..Day11::default()
+        };
+        assert!(day.part1(&Graph::parse(input).unwrap()).is_err());
+    }
+
+    /// Rectangular maps of empty space and galaxies
+    fn universe() -> impl Strategy<Value = String> {
+        (1..12usize, 1..12usize).prop_flat_map(|(rows, cols)| {
+            prop::collection::vec(
+                prop::collection::vec(prop::sample::select(vec!['.', '.', '.', '#']), cols),
+                rows,
+            )
+            .prop_map(|map| {
+                map.into_iter()
+                    .map(|row| row.into_iter().chain(['\n']).collect::<String>())
+                    .collect()
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_counting_matches_expanding(input in universe(), factor in 1..5usize) {
+            let g = Graph::parse(&input).unwrap();
+            let day = Day11 {
+                part1_expansion: factor,
+                ..Day11::default()
+            };
+            prop_assert_eq!(
+                day.part1(&g).unwrap(),
+                slow::all_pairs_shortest_distance(&g.map, factor).unwrap().to_string()
+            );
+        }
+    }
+}
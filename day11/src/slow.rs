@@ -0,0 +1,52 @@
+//! The original part 1 approach: physically expand the map, then measure. Kept to compare against
+//! the row/column counting in `Graph`.
+
+use crate::Day11;
+use anyhow::Result;
+use common::{arith, Solution};
+use grid::{Coordinate, Grid};
+use std::collections::HashSet;
+
+/// Repeats every empty row and column of `input` `factor` times
+fn expand(input: &Grid<char>, factor: usize) -> Result<Grid<char>> {
+    // mark which columns need repeating
+    let mut col_adds = HashSet::<usize>::new();
+    for (col, mut c) in input.iter_columns().enumerate() {
+        if !c.any(|&v| v == '#') {
+            col_adds.insert(col);
+        }
+    }
+
+    let mut expanded_map: Vec<Vec<char>> = Vec::new();
+    // loop through the rows, repeating empty ones, and make a new map based on repeated columns
+    for row in input.iter_rows() {
+        let mut r = Vec::new();
+        for (col, chr) in row.iter().enumerate() {
+            let times = if col_adds.contains(&col) { factor } else { 1 };
+            r.extend(std::iter::repeat_n(*chr, times));
+        }
+        let times = if row.contains(&'#') { 1 } else { factor };
+        expanded_map.extend(std::iter::repeat_n(r, times));
+    }
+    Ok(Grid::from_rows(expanded_map)?)
+}
+
+/// Compute the shortest distance between each galaxy after each empty row and column of `map`
+/// becomes `factor` of them
+pub fn all_pairs_shortest_distance(map: &Grid<char>, factor: usize) -> Result<u64> {
+    let expanded_map = expand(map, factor)?;
+
+    let galaxies = expanded_map
+        .iter()
+        .filter(|(_, &v)| v == '#')
+        .map(|(pos, _)| Coordinate::from(pos))
+        .collect::<Vec<_>>();
+
+    let mut sum = 0;
+    for (galaxy, a) in galaxies.iter().enumerate() {
+        for b in galaxies[galaxy + 1..].iter() {
+            sum = arith::add(Day11::DAY, "sum of distances", sum, a.manhattan(*b))?;
+        }
+    }
+    Ok(sum)
+}
@@ -0,0 +1,25 @@
+//! Random cube game logs
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// `size` games of one to six rounds, each showing up to 20 cubes of some of the colors
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=size {
+        let rounds = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                colors[..rng.gen_range(1..=3)]
+                    .iter()
+                    .map(|c| format!("{} {c}", rng.gen_range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        out.push_str(&format!("Game {id}: {}\n", rounds.join("; ")));
+    }
+    out
+}
@@ -0,0 +1,216 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::multispace0,
+    multi::separated_list0,
+    sequence::{preceded, separated_pair, terminated, tuple},
+    IResult,
+};
+
+use anyhow::Result;
+use common::config::{self, Table};
+use common::parse::{self, lines, number};
+use common::{arith, Error, Solution};
+use rand::rngs::StdRng;
+use serde::Deserialize;
+
+mod generate;
+
+/// Day 2: Cube Conundrum
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day2 {
+    /// Cubes of each color in the bag, which part 1 checks the games against
+    pub bag: Round,
+}
+
+impl Default for Day2 {
+    fn default() -> Self {
+        Self {
+            bag: Round {
+                red: 12,
+                green: 13,
+                blue: 14,
+            },
+        }
+    }
+}
+
+/// Number of cubes of one color shown in a round
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Color {
+    Red(i32),
+    Green(i32),
+    Blue(i32),
+}
+
+impl Color {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (remaining, (cnt, color)) = preceded(
+            multispace0,
+            separated_pair(
+                number,
+                tag(" "),
+                alt((tag("red"), tag("green"), tag("blue"))),
+            ),
+        )(input)?;
+
+        let ret = match color {
+            "red" => Color::Red(cnt),
+            "green" => Color::Green(cnt),
+            "blue" => Color::Blue(cnt),
+            // shouldn't get here because nom would error out
+            _ => {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Tag,
+                )))
+            }
+        };
+
+        Ok((remaining, ret))
+    }
+
+    // return true if the bag has enough cubes of the color
+    pub fn validate(&self, bag: &Round) -> bool {
+        match self {
+            Color::Red(val) => val <= &bag.red,
+            Color::Green(val) => val <= &bag.green,
+            Color::Blue(val) => val <= &bag.blue,
+        }
+    }
+}
+
+/// Cubes of each color shown at once
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Round {
+    pub red: i32,
+    pub green: i32,
+    pub blue: i32,
+}
+
+impl Round {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        //separated_list0(tag(","), Color::parse)(input)
+        let (remaining, colors) = separated_list0(tag(","), Color::parse)(input)?;
+        let mut r = Round::default();
+
+        for c in colors {
+            match c {
+                Color::Red(val) => r.red = val,
+                Color::Green(val) => r.green = val,
+                Color::Blue(val) => r.blue = val,
+            }
+        }
+
+        Ok((remaining, r))
+    }
+
+    // returns true if the round is within the bounds for each color
+    pub fn validate(&self, bag: &Round) -> bool {
+        Color::Red(self.red).validate(bag)
+            && Color::Green(self.green).validate(bag)
+            && Color::Blue(self.blue).validate(bag)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+fn parse_id(input: &str) -> IResult<&str, u32> {
+    terminated(preceded(tag("Game "), number), tag(": "))(input)
+}
+
+impl Game {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (remaining, (id, rounds)) =
+            tuple((parse_id, separated_list0(tag(";"), Round::parse)))(input)?;
+        Ok((remaining, Game { id, rounds }))
+    }
+
+    pub fn validate(&self, bag: &Round) -> bool {
+        // validate each round
+        for r in &self.rounds {
+            if !r.validate(bag) {
+                return false;
+            }
+        }
+        true
+    }
+
+    // get the max for each color amongst each round
+    pub fn power(&self) -> Result<i64, Error> {
+        let red = self.rounds.iter().map(|r| r.red).max();
+        let green = self.rounds.iter().map(|r| r.green).max();
+        let blue = self.rounds.iter().map(|r| r.blue).max();
+        arith::product(
+            Day2::DAY,
+            "power",
+            [red, green, blue].map(|c| i64::from(c.unwrap_or(0))),
+        )
+    }
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse::all(Self::DAY, "games", input, lines(Game::parse))?)
+    }
+
+    fn part1(&self, games: &Self::Input) -> Result<String> {
+        let mut id_sum = 0u64;
+
+        for g in games {
+            if g.validate(&self.bag) {
+                id_sum = arith::add(Self::DAY, "sum of game ids", id_sum, g.id.into())?;
+            }
+        }
+
+        Ok(id_sum.to_string())
+    }
+
+    fn part2(&self, games: &Self::Input) -> Result<String> {
+        let mut power_sum = 0;
+
+        for g in games {
+            let power = g.power()?;
+            power_sum = arith::add(Self::DAY, "sum of powers", power_sum, power)?;
+        }
+
+        Ok(power_sum.to_string())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    /// `bag = { red = 12, green = 13, blue = 14 }`
+    fn configure(&mut self, params: Table) -> Result<()> {
+        *self = config::params(Self::DAY, params)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{assert_examples, Part};
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day2::default(),
+            &[
+                (include_str!("../test1.txt"), Part::One, "8"),
+                (include_str!("../test1.txt"), Part::Two, "2286"),
+            ],
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! Random engine schematics
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+
+/// A `size` by `size` schematic of mostly '.', with numbers of one to three digits and a
+/// scattering of symbols. Numbers on a row are always separated by at least one non-digit.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut row = Vec::with_capacity(size);
+        while row.len() < size {
+            let roll = rng.gen_range(0..10);
+            let last_digit = row.last().is_some_and(u8::is_ascii_digit);
+            if roll < 2 && !last_digit {
+                let len = rng.gen_range(1..=3).min(size - row.len());
+                row.push(rng.gen_range(b'1'..=b'9'));
+                row.extend((1..len).map(|_| rng.gen_range(b'0'..=b'9')));
+            } else if roll < 3 {
+                row.push(*SYMBOLS.choose(rng).unwrap());
+            } else {
+                row.push(b'.');
+            }
+        }
+        out.extend(row.into_iter().map(char::from));
+        out.push('\n');
+    }
+    out
+}
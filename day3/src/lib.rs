@@ -0,0 +1,197 @@
+use anyhow::Result;
+use common::{arith, Error, Solution};
+use grid::{Coordinate, Direction, Grid};
+use rand::rngs::StdRng;
+use std::collections::{BTreeSet, HashMap};
+
+mod generate;
+
+/// Day 3: Gear Ratios
+pub struct Day3;
+
+/// A number in the schematic and the positions of its first and last digit
+#[derive(Debug, Copy, Clone)]
+pub struct Num {
+    pub value: i64,
+    pub start: Coordinate,
+    pub end: Coordinate,
+}
+
+impl Num {
+    /// The number `num` ending just before `col_num` of row `row_num`
+    pub fn new(num: String, row_num: usize, col_num: usize) -> Result<Self> {
+        let r = row_num as i64;
+        let c = col_num as i64;
+        Ok(Num {
+            value: num.parse().map_err(|e| {
+                Error::parse_at_position(Day3::DAY, row_num, col_num - num.len(), format!("{e}"))
+            })?,
+            start: Coordinate::new(r, c - num.len() as i64),
+            end: Coordinate::new(r, c - 1),
+        })
+    }
+
+    /// Positions of the digits making up the number
+    pub fn digits(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        (self.start.col..=self.end.col).map(|col| Coordinate::new(self.start.row, col))
+    }
+
+    /// Every position touching the number, including diagonally
+    pub fn border(&self) -> BTreeSet<Coordinate> {
+        self.digits()
+            .flat_map(|d| Direction::ALL.map(|dir| d.step(dir)))
+            .filter(|pos| {
+                pos.row != self.start.row || pos.col < self.start.col || pos.col > self.end.col
+            })
+            .collect()
+    }
+}
+
+/// Returns true if `sym` is a symbol marking a part number
+pub fn is_symbol(sym: char) -> bool {
+    !sym.is_ascii_digit() && sym != '.'
+}
+
+fn gear_insert(gears: &mut HashMap<Coordinate, [i64; 2]>, pos: Coordinate, val: i64) {
+    match gears.get_mut(&pos) {
+        Some(v) => {
+            if v[1] != 0 {
+                // this is a bad case. only two exact.. actually remove this from the map
+                gears.remove(&pos);
+                return;
+            }
+            // v[0] should have another value already
+            v[1] = val;
+        }
+        None => {
+            // create v[0] and insert
+            let a = [val, 0];
+            let _ = gears.insert(pos, a);
+        }
+    }
+}
+
+/// The engine schematic: every number found along with the raw character map around them
+pub struct Schematic {
+    numbers: Vec<Num>,
+    char_map: Grid<char>,
+}
+
+impl Schematic {
+    pub fn numbers(&self) -> &[Num] {
+        &self.numbers
+    }
+
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut numbers = Vec::new();
+
+        for (row_num, row) in input.lines().enumerate() {
+            let mut num = String::new();
+            for (col_num, c) in row.chars().enumerate() {
+                match c {
+                    '0'..='9' => {
+                        num.push(c);
+                    }
+                    _ => {
+                        if !num.is_empty() {
+                            numbers.push(Num::new(num, row_num, col_num)?);
+                            num = String::new();
+                        }
+                    }
+                }
+            }
+            if !num.is_empty() {
+                numbers.push(Num::new(num, row_num, row.chars().count())?);
+            }
+        }
+
+        let char_map = Grid::parse(input).map_err(|e| Error::grid(Day3::DAY, e))?;
+
+        Ok(Self { numbers, char_map })
+    }
+
+    /// Walks around every number looking for adjacent symbols. Returns the sum of the part
+    /// numbers and the numbers touching each '*'
+    pub fn scan(&self) -> Result<(i64, HashMap<Coordinate, [i64; 2]>), Error> {
+        // DS for stars to compute gear ratios
+        let mut gears: HashMap<Coordinate, [i64; 2]> = HashMap::new();
+
+        let mut part_sum = 0;
+
+        for n in &self.numbers {
+            let mut add_val = false;
+            for pos in n.border() {
+                match self.char_map.at(pos) {
+                    Some(&sym) if is_symbol(sym) => {
+                        add_val = true;
+                        if sym == '*' {
+                            gear_insert(&mut gears, pos, n.value);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            if add_val {
+                // only add once
+                part_sum = arith::add(Day3::DAY, "sum of part numbers", part_sum, n.value)?;
+            }
+        }
+
+        Ok((part_sum, gears))
+    }
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Schematic::parse(input)
+    }
+
+    fn part1(&self, schematic: &Self::Input) -> Result<String> {
+        let (part_sum, _gears) = schematic.scan()?;
+        Ok(part_sum.to_string())
+    }
+
+    fn part2(&self, schematic: &Self::Input) -> Result<String> {
+        let (_part_sum, gears) = schematic.scan()?;
+        // now we should loop through the values of gears and multiply them then sum them (if
+        // there is only one, there should be a 0, so no impact on the sum
+        let ratios = gears
+            .values()
+            .map(|a| arith::mul(Self::DAY, "gear ratio", a[0], a[1]))
+            .collect::<Result<Vec<_>, _>>()?;
+        let gear_sum = arith::sum(Self::DAY, "sum of gear ratios", ratios)?;
+        Ok(gear_sum.to_string())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{assert_examples, Part};
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day3,
+            &[
+                (include_str!("../test1.txt"), Part::One, "4361"),
+                (include_str!("../test1.txt"), Part::Two, "467835"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_columns_count_chars() {
+        // a number ending its row after a multi-byte character still ends where it's drawn
+        assert_examples(&Day3, &[("é12\n*..\n", Part::One, "12")]);
+    }
+}
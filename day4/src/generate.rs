@@ -0,0 +1,42 @@
+//! Random scratchcards
+
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+const WINNING: usize = 10;
+const OURS: usize = 25;
+
+/// `size` cards of 10 winning numbers and 25 of ours. Most cards win nothing and the rest match
+/// at most three numbers, so the copies won in part 2 stay small however long the pile is.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut out = String::new();
+    for i in 0..size {
+        let matches = if rng.gen_bool(0.75) {
+            0
+        } else {
+            rng.gen_range(1..=3).min(size - 1 - i)
+        };
+        let picked: Vec<usize> = index::sample(rng, 99, WINNING + OURS - matches)
+            .into_iter()
+            .map(|n| n + 1)
+            .collect();
+        let (winning, rest) = picked.split_at(WINNING);
+        let mut ours = rest.to_vec();
+        ours.extend_from_slice(&winning[..matches]);
+        ours.shuffle(rng);
+        let fmt = |nums: &[usize]| {
+            nums.iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        out.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            i + 1,
+            fmt(winning),
+            fmt(&ours)
+        ));
+    }
+    out
+}
@@ -0,0 +1,176 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{space0, space1},
+    sequence::{pair, separated_pair},
+    IResult, Parser,
+};
+
+use anyhow::Result;
+use common::parse::{self, key_value, lines, number, numbers};
+use common::{arith, Error, Solution};
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+mod generate;
+
+/// Day 4: Scratchcards
+pub struct Day4;
+
+/// One side of a scratchcard
+#[derive(Debug, Clone)]
+pub struct CardSet {
+    cards: HashSet<i64>,
+}
+
+impl CardSet {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        numbers
+            .map(|cards: Vec<i64>| Self {
+                cards: cards.into_iter().collect(),
+            })
+            .parse(input)
+    }
+
+    pub fn get_set(&self) -> &HashSet<i64> {
+        &self.cards
+    }
+}
+
+/// Copies held of each card, by index. Copies can double with every card, so they're counted in
+/// 64 bits whatever the platform.
+#[derive(Debug, Clone, Default)]
+pub struct CardCounter {
+    cards: HashMap<usize, u64>,
+}
+
+impl CardCounter {
+    pub fn new() -> Self {
+        Self {
+            cards: HashMap::new(),
+        }
+    }
+
+    /// Count the original of `card`
+    pub fn init_card(&mut self, card: usize) {
+        if let Some(v) = self.cards.get_mut(&card) {
+            *v += 1;
+        } else {
+            self.cards.insert(card, 1);
+        }
+    }
+
+    /// Every copy of `card` wins a copy of the card `copy + 1` after it
+    pub fn add_copy(&mut self, card: usize, copy: usize) -> Result<(), Error> {
+        let x = match self.cards.get(&card) {
+            // return 0 if the card hasn't been visited yet
+            None => 0,
+            // use stored card value
+            Some(v) => *v,
+        };
+
+        // if we don't have the card already, insert it with the current card's value, otherwise
+        // add to the value currently there
+        match self.cards.get_mut(&(card + 1 + copy)) {
+            None => {
+                self.cards.insert(card + 1 + copy, x);
+            }
+            Some(cval) => {
+                *cval = arith::add(Day4::DAY, "copies of a card", *cval, x)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_cards(&self) -> &HashMap<usize, u64> {
+        &self.cards
+    }
+}
+
+/// Every scratchcard as its (winning numbers, numbers we have) pair
+pub struct Scratchcards {
+    rounds: Vec<(CardSet, CardSet)>,
+}
+
+impl Scratchcards {
+    pub fn parse(input: &str) -> Result<Self> {
+        // Card  1: 41 48 83 | 83 86  6
+        let card = key_value(
+            pair(tag("Card"), space1.and(number::<usize, _>)),
+            ":",
+            separated_pair(CardSet::parse, pair(space0, tag("|")), CardSet::parse),
+        );
+        let cards = parse::all(Day4::DAY, "scratchcards", input, lines(card))?;
+        Ok(Self {
+            rounds: cards.into_iter().map(|(_, round)| round).collect(),
+        })
+    }
+
+    /// Number of our numbers that are also winning numbers for each card
+    pub fn matches(&self) -> impl Iterator<Item = usize> + '_ {
+        self.rounds
+            .iter()
+            .map(|(winners, ours)| winners.get_set().intersection(ours.get_set()).count())
+    }
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Scratchcards;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Scratchcards::parse(input)
+    }
+
+    fn part1(&self, cards: &Self::Input) -> Result<String> {
+        let mut winning_sum = 0;
+
+        for inter in cards.matches() {
+            if inter == 0 {
+                continue;
+            }
+            let points = arith::pow(Self::DAY, "points", 2i64, inter - 1)?;
+            winning_sum = arith::add(Self::DAY, "sum of points", winning_sum, points)?;
+        }
+
+        Ok(winning_sum.to_string())
+    }
+
+    fn part2(&self, cards: &Self::Input) -> Result<String> {
+        let mut cc = CardCounter::new();
+
+        for (i, inter) in cards.matches().enumerate() {
+            cc.init_card(i);
+
+            // add copies of cards if we won
+            for j in 0..inter {
+                cc.add_copy(i, j)?;
+            }
+        }
+
+        let total = arith::sum(Self::DAY, "total cards", cc.get_cards().values().copied())?;
+        Ok(total.to_string())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{assert_examples, Part};
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day4,
+            &[
+                (include_str!("../test1.txt"), Part::One, "13"),
+                (include_str!("../test1.txt"), Part::Two, "30"),
+            ],
+        );
+    }
+}
@@ -0,0 +1,43 @@
+//! Random almanacs
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Names of the seven maps, in order
+pub(crate) const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Every number stays below this, like the real inputs
+const LIMIT: u64 = 1 << 32;
+
+/// `size` seed ranges and seven maps of `size` ranges each. The sources of each map are split
+/// into `size` equal slots with at most one range inside each, so no two ranges overlap.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1) as u64;
+    let slot = LIMIT / size;
+    let seeds = (0..size)
+        .map(|_| {
+            let len = rng.gen_range(1..=slot);
+            format!("{} {len}", rng.gen_range(0..=LIMIT - len))
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut out = format!("seeds: {seeds}\n");
+    for name in MAPS {
+        out.push_str(&format!("\n{name} map:\n"));
+        for i in 0..size {
+            let len = rng.gen_range(1..=slot);
+            let source = i * slot + rng.gen_range(0..=slot - len);
+            let dest = rng.gen_range(0..=LIMIT - len);
+            out.push_str(&format!("{dest} {source} {len}\n"));
+        }
+    }
+    out
+}
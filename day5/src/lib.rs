@@ -0,0 +1,525 @@
+use nom::{character::complete::multispace1, combinator::verify, sequence::separated_pair};
+
+use anyhow::{Context, Result};
+use common::parse::{self, blocks, labelled, lines, numbers, section};
+use common::repl::Command;
+use common::{Error, Solution};
+use rand::rngs::StdRng;
+use rayon::prelude::*;
+use std::cmp::{max, min};
+use std::collections::VecDeque;
+
+mod generate;
+
+/// Day 5: If You Give A Seed A Fertilizer
+pub struct Day5;
+
+/// Trait that describes an Almanac Map that can convert a source number to the destination
+pub trait AlmanacConverter {
+    fn convert(&self, source: u64) -> u64;
+}
+
+/// Type that describes the entire almanac
+pub struct Almanac {
+    init_seeds: Vec<u64>,
+    seed_soil: AlMap,
+    soil_fert: AlMap,
+    fert_water: AlMap,
+    water_light: AlMap,
+    light_temp: AlMap,
+    temp_humid: AlMap,
+    humid_loc: AlMap,
+}
+
+impl Almanac {
+    /// Parse raw challenge input into an Almanac
+    pub fn parse(input: &str) -> Result<Self> {
+        // the init seeds, then a blank line separated section of ranges for each map
+        let (init_seeds, sections) = parse::all(
+            Day5::DAY,
+            "almanac",
+            input,
+            separated_pair(
+                labelled("seeds", numbers),
+                multispace1,
+                blocks(section(lines(verify(numbers, |r: &Vec<u64>| r.len() == 3)))),
+            ),
+        )?;
+        let found = sections.len();
+
+        let mut maps = sections
+            .into_iter()
+            .map(|(_name, ranges)| AlMap::from_numbers(&ranges))
+            .collect::<Result<VecDeque<_>>>()?;
+        let mut next_map = || {
+            maps.pop_front()
+                .ok_or_else(|| Error::invalid(Day5::DAY, format!("expected 7 maps, found {found}")))
+        };
+
+        Ok(Self {
+            init_seeds,
+            seed_soil: next_map()?,
+            soil_fert: next_map()?,
+            fert_water: next_map()?,
+            water_light: next_map()?,
+            light_temp: next_map()?,
+            temp_humid: next_map()?,
+            humid_loc: next_map()?,
+        })
+    }
+
+    pub fn seeds(&self) -> &[u64] {
+        &self.init_seeds
+    }
+
+    /// Returns the conversions for the initial seeds
+    pub fn get_conversions(&self) -> Vec<u64> {
+        let mut conversions = Vec::with_capacity(self.init_seeds.len());
+
+        for seed in &self.init_seeds {
+            conversions.push(self.get_conversion(*seed));
+        }
+
+        conversions
+    }
+
+    pub fn get_conversion(&self, seed: u64) -> u64 {
+        let soil = self.seed_soil.convert(seed);
+        let fert = self.soil_fert.convert(soil);
+        let water = self.fert_water.convert(fert);
+        let light = self.water_light.convert(water);
+        let temp = self.light_temp.convert(light);
+        let humid = self.temp_humid.convert(temp);
+        self.humid_loc.convert(humid)
+    }
+
+    /// Use init seeds as a range instead of the starting points
+    pub fn part2(&self) -> Result<Option<(u64, u64)>, Error> {
+        // start by making a set of ranges to start with
+        let chunks = self.init_seeds.chunks_exact(2);
+        if !chunks.remainder().is_empty() {
+            return Err(Error::invalid(
+                Day5::DAY,
+                format!(
+                    "seed ranges need pairs of numbers, found {}",
+                    self.init_seeds.len()
+                ),
+            ));
+        }
+        let init_ranges: Vec<(u64, u64)> = chunks.map(|chunk| (chunk[0], chunk[1])).collect();
+        for &(start, len) in &init_ranges {
+            if start.checked_add(len).is_none() {
+                return Err(Error::invalid(
+                    Day5::DAY,
+                    format!("seed range {start} {len} ends past {}", u64::MAX),
+                ));
+            }
+        }
+
+        // each seed range goes through the maps on its own, so they can be split across threads
+        Ok(init_ranges
+            .into_par_iter()
+            .filter_map(|range| {
+                // for each of the ranges, output the min values from that translation
+                let mut ranges = vec![range];
+                ranges = Almanac::map_ranges(ranges, &self.seed_soil);
+                ranges = Almanac::map_ranges(ranges, &self.soil_fert);
+                ranges = Almanac::map_ranges(ranges, &self.fert_water);
+                ranges = Almanac::map_ranges(ranges, &self.water_light);
+                ranges = Almanac::map_ranges(ranges, &self.light_temp);
+                ranges = Almanac::map_ranges(ranges, &self.temp_humid);
+                ranges = Almanac::map_ranges(ranges, &self.humid_loc);
+                ranges.into_iter().min_by_key(|x| x.0)
+            })
+            .min_by_key(|x| x.0))
+    }
+
+    pub fn map_ranges(mut init_ranges: Vec<(u64, u64)>, map: &AlMap) -> Vec<(u64, u64)> {
+        // vec to store the eventual results for this mapping
+        let mut final_ranges: Vec<(u64, u64)> = vec![];
+
+        // process ranges until we run out in init_ranges
+        while let Some(src_rng) = init_ranges.pop() {
+            // for each of this map's translations, we need to determine if there is a range that
+            // overlaps
+            // need to keep track if we find an overlap for the given range (if not, then we just
+            // add the identity mapping to final_ranges
+            let mut overlap_found = false;
+            for translation in &map.ranges {
+                // overlaps for the src_rng (from init_ranges) and the rng (from the map
+                // translation)
+                let overlap_start = max(src_rng.0, translation.source_start);
+                let overlap_end = min(
+                    src_rng.0 + src_rng.1,
+                    translation.source_start + translation.range_len as u64,
+                );
+
+                // if we have an overlap, then we can put that whole range in final_ranges
+                if overlap_start < overlap_end {
+                    // range that moves on is starting
+                    final_ranges.push((
+                        overlap_start - translation.source_start + translation.dest_start,
+                        overlap_end - overlap_start,
+                    ));
+                    // check to see if there are leftovers from the source rang
+                    if src_rng.0 < overlap_start {
+                        init_ranges.push((src_rng.0, overlap_start - src_rng.0));
+                    }
+                    if overlap_end < src_rng.0 + src_rng.1 {
+                        init_ranges.push((overlap_end, (src_rng.0 + src_rng.1) - overlap_end));
+                    }
+                    overlap_found = true;
+                    break;
+                }
+            }
+            if !overlap_found {
+                // add identity mapping
+                final_ranges.push(src_rng);
+            }
+        }
+        final_ranges
+    }
+}
+
+/// Generic Almanac Map type that implements the convert trait
+pub struct AlMap {
+    /// The ranges for this specific map
+    ranges: Vec<Range>,
+}
+
+impl AlMap {
+    /// Given a list of range strings, parse into an AlMap
+    pub fn parse(input: Vec<&str>) -> Result<Self> {
+        let mut ranges = Vec::with_capacity(input.len());
+
+        for r in input {
+            ranges.push(Range::parse(r)?);
+        }
+        Ok(Self { ranges })
+    }
+
+    /// Build an AlMap from each range's already parsed numbers
+    pub fn from_numbers(input: &[Vec<u64>]) -> Result<Self> {
+        let mut ranges = Vec::with_capacity(input.len());
+
+        for r in input {
+            ranges.push(Range::from_numbers(r)?);
+        }
+        Ok(Self { ranges })
+    }
+}
+
+impl AlmanacConverter for AlMap {
+    /// Converts a source to the destination
+    fn convert(&self, source: u64) -> u64 {
+        // iterate over each range, checking to see if it is in the range
+        for rng in &self.ranges {
+            match rng.in_range(source) {
+                Some(dest) => {
+                    return dest;
+                }
+                None => {
+                    continue;
+                }
+            }
+        }
+
+        // if here, never found one in range, so it's source
+        source
+    }
+}
+
+/// Type that describes a range with a source, destination, and length
+#[derive(Debug, PartialEq, Eq)]
+pub struct Range {
+    source_start: u64,
+    dest_start: u64,
+    range_len: usize,
+}
+
+impl Range {
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::from_numbers(&parse::all(Day5::DAY, "range", input, numbers)?)
+    }
+
+    /// Build a range from its destination start, source start and length
+    pub fn from_numbers(nums: &[u64]) -> Result<Self> {
+        match nums {
+            [dest_start, source_start, range_len] => {
+                // then nothing the range maps to or from is out of bounds
+                for start in [source_start, dest_start] {
+                    if start.checked_add(*range_len).is_none() {
+                        return Err(Error::invalid(
+                            Day5::DAY,
+                            format!("range {start} {range_len} ends past {}", u64::MAX),
+                        )
+                        .into());
+                    }
+                }
+                Ok(Self {
+                    source_start: *source_start,
+                    dest_start: *dest_start,
+                    range_len: (*range_len).try_into()?,
+                })
+            }
+            _ => Err(Error::invalid(
+                Day5::DAY,
+                format!("a range needs 3 numbers, found {}", nums.len()),
+            )
+            .into()),
+        }
+    }
+
+    /// Returns Some(destination) if source is in range, None if not
+    /// in range
+    pub fn in_range(&self, source: u64) -> Option<u64> {
+        if source >= self.source_start && source < self.source_start + self.range_len as u64 {
+            Some(self.dest_start + (source - self.source_start))
+        } else {
+            None
+        }
+    }
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Almanac::parse(input)
+    }
+
+    fn part1(&self, alm: &Self::Input) -> Result<String> {
+        let conversions = alm.get_conversions();
+        Ok(conversions
+            .iter()
+            .min()
+            .ok_or(Error::invalid(Self::DAY, "no seeds"))?
+            .to_string())
+    }
+
+    fn part2(&self, alm: &Self::Input) -> Result<String> {
+        // for part 2, we should convert the init seeds to a larger vec and then re-run
+        // get_conversions()
+        let min_loc = alm
+            .part2()?
+            .ok_or(Error::invalid(Self::DAY, "no seed ranges"))?;
+        Ok(min_loc.0.to_string())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn commands(&self) -> Vec<Command<Self>> {
+        vec![Command {
+            name: "convert",
+            args: "<seed>",
+            help: "the location a seed ends up at",
+            run: |_, alm, args| {
+                let seed: u64 = args[0]
+                    .parse()
+                    .with_context(|| format!("invalid seed '{}'", args[0]))?;
+                Ok(alm.get_conversion(seed).to_string())
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{assert_examples, Part, Puzzle};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day5,
+            &[
+                (include_str!("../test1.txt"), Part::One, "35"),
+                (include_str!("../test1.txt"), Part::Two, "46"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let input = "52 50 48";
+        let rng = Range::parse(input).unwrap();
+        assert_eq!(rng.source_start, 50);
+        assert_eq!(rng.dest_start, 52);
+        assert_eq!(rng.range_len, 48);
+    }
+
+    #[test]
+    fn test_in_range() {
+        let input = "52 50 48";
+        let rng = Range::parse(input).unwrap();
+        assert_eq!(rng.in_range(79), Some(81));
+        assert_eq!(rng.in_range(14), None);
+        assert_eq!(rng.in_range(55), Some(57));
+        assert_eq!(rng.in_range(13), None);
+    }
+
+    #[test]
+    fn test_parse_almap() {
+        let input = "50 98 2\n52 50 48".split('\n').collect::<Vec<&str>>();
+        let map = AlMap::parse(input).unwrap();
+        assert_eq!(map.ranges.len(), 2);
+        assert_eq!(map.ranges.first().unwrap().source_start, 98);
+    }
+
+    #[test]
+    fn test_converter() {
+        let input = "50 98 2\n52 50 48".split('\n').collect::<Vec<&str>>();
+        let map = AlMap::parse(input).unwrap();
+
+        assert_eq!(map.convert(79), 81);
+        assert_eq!(map.convert(14), 14);
+        assert_eq!(map.convert(55), 57);
+        assert_eq!(map.convert(13), 13);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+        let alm = Almanac::parse(input).unwrap();
+
+        assert_eq!(alm.init_seeds, Vec::from([79, 14, 55, 13]));
+        let seed_soil_inp = "50 98 2\n52 50 48".split('\n').collect::<Vec<&str>>();
+        assert_eq!(
+            alm.seed_soil.ranges.first().unwrap(),
+            AlMap::parse(seed_soil_inp).unwrap().ranges.first().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_bad_range() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n";
+        match Almanac::parse(input) {
+            Ok(_) => panic!("parsed a range with two numbers"),
+            Err(e) => assert!(e.to_string().starts_with("day 5 input line 5,"), "{e}"),
+        }
+    }
+
+    #[test]
+    fn test_ranges_past_u64() {
+        assert!(Range::from_numbers(&[u64::MAX - 1, 0, 2]).is_err());
+        assert!(Range::from_numbers(&[0, u64::MAX, 1]).is_err());
+        assert!(Range::from_numbers(&[0, u64::MAX - 1, 1]).is_ok());
+
+        let almanac = Almanac::parse(&include_str!("../test1.txt").replacen(
+            "seeds: 79 14",
+            "seeds: 18446744073709551615 5",
+            1,
+        ))
+        .unwrap();
+        assert!(almanac.part2().is_err());
+    }
+
+    #[test]
+    fn test_convert_command() {
+        let mut out = Vec::new();
+        let commands = "convert 79\nconvert 14\nconvert seventy\n";
+        Day5.repl(
+            include_str!("../test1.txt"),
+            &mut commands.as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let replies: Vec<&str> = out.lines().skip(1).collect();
+        assert_eq!(
+            replies,
+            [
+                "day5> 82",
+                "day5> 43",
+                "day5> error: invalid seed 'seventy': invalid digit found in string",
+                "day5> ",
+            ]
+        );
+    }
+
+    /// One to four non-overlapping ranges, each inside its own 60 wide slot of sources
+    fn al_map() -> impl Strategy<Value = String> {
+        prop::collection::vec((any::<bool>(), 0..300u64, 0..10u64, 1..50u64), 4).prop_map(|slots| {
+            slots
+                .into_iter()
+                .enumerate()
+                .filter(|(i, (used, ..))| *used || *i == 0)
+                .map(|(i, (_, dest, offset, len))| {
+                    format!("{dest} {} {len}\n", i as u64 * 60 + offset)
+                })
+                .collect()
+        })
+    }
+
+    /// An almanac with a few seed ranges and all seven maps
+    fn almanac() -> impl Strategy<Value = String> {
+        (
+            prop::collection::vec((0..250u64, 1..30u64), 1..4),
+            prop::collection::vec(al_map(), 7),
+        )
+            .prop_map(|(seeds, maps)| {
+                let seeds = seeds
+                    .iter()
+                    .map(|(start, len)| format!("{start} {len}"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let mut input = format!("seeds: {seeds}\n");
+                for (name, ranges) in generate::MAPS.iter().zip(maps) {
+                    input.push_str(&format!("\n{name} map:\n{ranges}"));
+                }
+                input
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_ranges_match_brute_force(input in almanac()) {
+            let alm = Almanac::parse(&input).unwrap();
+            let brute_force = alm
+                .init_seeds
+                .chunks(2)
+                .flat_map(|c| c[0]..c[0] + c[1])
+                .map(|seed| alm.get_conversion(seed))
+                .min();
+            prop_assert_eq!(alm.part2().unwrap().map(|r| r.0), brute_force);
+        }
+    }
+}
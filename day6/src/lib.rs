@@ -0,0 +1,237 @@
+use nom::{character::complete::multispace0, sequence::preceded, IResult};
+
+use anyhow::Result;
+use common::bench::Alternative;
+use common::parse::{self, labelled, numbers};
+use common::{arith, Error, Part, Solution};
+
+/// Day 6: Wait For It
+pub struct Day6;
+
+/// Struct for different races
+#[derive(Debug)]
+pub struct IslandRaces {
+    races: Vec<Race>,
+}
+
+impl IslandRaces {
+    pub fn parse(input: &str) -> IResult<&str, (Vec<i64>, Vec<i64>)> {
+        // parse times (discard any whitespace before 'Time:')
+        let (input, times) = preceded(multispace0, labelled("Time", numbers))(input)?;
+
+        // parse distances (discared any whitespace before 'Distance:'; like a \n)
+        let (remaining, distances) = preceded(multispace0, labelled("Distance", numbers))(input)?;
+
+        log::debug!("times: {times:?}");
+        log::debug!("dists: {distances:?}");
+
+        Ok((remaining, (times, distances)))
+    }
+
+    /// Pair up each race's time and record distance
+    pub fn new(times: Vec<i64>, distances: Vec<i64>) -> Result<Self, Error> {
+        if times.len() != distances.len() {
+            return Err(Error::invalid(
+                Day6::DAY,
+                format!(
+                    "found {} times but {} distances",
+                    times.len(),
+                    distances.len()
+                ),
+            ));
+        }
+
+        // zip them up
+        let races = times
+            .iter()
+            .zip(distances)
+            .map(|(t, d)| Race::new(*t, d))
+            .collect();
+
+        Ok(IslandRaces { races })
+    }
+
+    pub fn compute_records(&self) -> Result<i64, Error> {
+        // compute each race's possible ways to win, then multiply them all
+        let ways = self
+            .races
+            .iter()
+            .map(Race::ways_to_win)
+            .collect::<Result<Vec<_>, _>>()?;
+        arith::product(Day6::DAY, "ways to win", ways)
+    }
+
+    /// Combines every race into the one big race
+    pub fn remove_kerning(&self) -> Result<BigRace> {
+        // convert to a string to just combine and avoid ugly decimal math
+        // also, need to update the races to support larger numbers
+        let new_time = self
+            .races
+            .iter()
+            .fold(String::new(), |mut acc, x| {
+                let s = x.total_time.to_string();
+                acc.push_str(s.as_str());
+                acc
+            })
+            .parse::<u64>()
+            .map_err(|e| Error::invalid(Day6::DAY, format!("combined time: {e}")))?;
+
+        let new_dist = self
+            .races
+            .iter()
+            .fold(String::new(), |mut acc, x| {
+                let s = x.record_dist.to_string();
+                acc.push_str(s.as_str());
+                acc
+            })
+            .parse::<u64>()
+            .map_err(|e| Error::invalid(Day6::DAY, format!("combined distance: {e}")))?;
+
+        log::debug!("New Time: {new_time:?}");
+        log::debug!("New Dist: {new_dist:?}");
+
+        Ok(BigRace::new(new_time, new_dist))
+    }
+}
+
+/// Struct to describe a race
+#[derive(Debug)]
+pub struct Race {
+    total_time: i64,
+    record_dist: i64,
+}
+
+impl Race {
+    pub fn new(total_time: i64, record_dist: i64) -> Self {
+        Race {
+            total_time,
+            record_dist,
+        }
+    }
+
+    pub fn ways_to_win(&self) -> Result<i64, Error> {
+        let mut ways = 0;
+        for ms_held in 0..=self.total_time {
+            let dist = arith::mul(
+                Day6::DAY,
+                "distance of a race",
+                self.total_time - ms_held,
+                ms_held,
+            )?;
+            if dist > self.record_dist {
+                ways += 1;
+            }
+        }
+        Ok(ways)
+    }
+}
+
+/// The single race read once the kerning between the numbers is ignored. Distances are up to
+/// the square of the time, so they're compared in 128 bits.
+#[derive(Debug)]
+pub struct BigRace {
+    total_time: u64,
+    record_dist: u64,
+}
+
+impl BigRace {
+    pub fn new(total_time: u64, record_dist: u64) -> Self {
+        BigRace {
+            total_time,
+            record_dist,
+        }
+    }
+
+    /// Whether holding the button for `ms_held` beats the record
+    fn wins(&self, ms_held: u64) -> bool {
+        u128::from(self.total_time - ms_held) * u128::from(ms_held) > u128::from(self.record_dist)
+    }
+
+    pub fn naive(&self) -> u64 {
+        (0..=self.total_time)
+            .filter(|&ms_held| self.wins(ms_held))
+            .count() as u64
+    }
+
+    pub fn smart(&self) -> u64 {
+        // can also just find the first occurence from the start of the range and then from the
+        // back
+        let mut first = None;
+        for s in 0..=self.total_time {
+            if self.wins(s) {
+                first = Some(s);
+                break;
+            }
+        }
+        // no way to win at all
+        let Some(first) = first else {
+            return 0;
+        };
+        let mut second = 0;
+        for s in (0..=self.total_time).rev() {
+            if self.wins(s) {
+                second = s;
+                break;
+            }
+        }
+
+        second - first + 1
+    }
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = IslandRaces;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        // good opportunity to use nom because there isn't a clean break in the input
+        let (times, distances) = parse::all(Self::DAY, "races", input, IslandRaces::parse)?;
+        let r = IslandRaces::new(times, distances)?;
+        log::debug!("Races = {r:?}");
+        Ok(r)
+    }
+
+    fn part1(&self, r: &Self::Input) -> Result<String> {
+        Ok(r.compute_records()?.to_string())
+    }
+
+    fn part2(&self, r: &Self::Input) -> Result<String> {
+        // part 2 is annoying, but shouldn't be too bad. just need to convert the races vec in r
+        Ok(r.remove_kerning()?.smart().to_string())
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        vec![Alternative {
+            name: "naive",
+            part: Part::Two,
+            solve: |_, r| Ok(r.remove_kerning()?.naive().to_string()),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::assert_examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day6,
+            &[
+                (include_str!("../test.txt"), Part::One, "288"),
+                (include_str!("../test.txt"), Part::Two, "71503"),
+            ],
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_smart_matches_naive(total_time in 0..2_000u64, record in 0..1_100_000u64) {
+            let race = BigRace::new(total_time, record);
+            prop_assert_eq!(race.smart(), race.naive());
+        }
+    }
+}
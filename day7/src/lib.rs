@@ -0,0 +1,518 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, multispace0},
+    combinator::verify,
+    multi::many0,
+    sequence::preceded,
+    sequence::separated_pair,
+    IResult,
+};
+
+use anyhow::{bail, Result};
+use common::config::{self, Table};
+use common::parse;
+use common::repl::Command;
+use common::{arith, Error, Solution};
+use serde::Deserialize;
+
+use std::cmp::Ordering;
+
+use std::collections::HashMap;
+
+/// Day 7: Camel Cards
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day7 {
+    /// Card strength from weakest to strongest in part 1
+    pub card_order: String,
+    /// Card strength from weakest to strongest in part 2, where 'J' is a joker
+    pub joker_order: String,
+}
+
+impl Default for Day7 {
+    fn default() -> Self {
+        Self {
+            card_order: String::from_iter(CARD_ORDER),
+            joker_order: String::from_iter(JOKER_ORDER),
+        }
+    }
+}
+
+/// Helper method that splits up a &str into its logical chunks (i.e.: will return a vec with its
+/// characters and the count in the string
+pub fn chunk_string(input: &str) -> HashMap<char, usize> {
+    let mut m = HashMap::new();
+    for c in input.chars() {
+        match m.get_mut(&c) {
+            None => {
+                let _ = m.insert(c, 1);
+            }
+            Some(count) => {
+                *count += 1;
+            }
+        }
+    }
+    m
+}
+
+/// Kind of hand, from five of a kind down to high card
+#[derive(Debug, Clone, Eq)]
+pub enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+/// Custom ord for HandType that leverages an internal value for the comparison
+impl Ord for HandType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let self_value = self.card_value();
+        let other_value = other.card_value();
+
+        self_value.cmp(&other_value)
+    }
+}
+
+impl PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for HandType {
+    fn eq(&self, other: &Self) -> bool {
+        self.card_value() == other.card_value()
+    }
+}
+
+impl HandType {
+    /// Creates a new HandType based on the provided card
+    pub fn new(card: &str) -> Self {
+        let m = chunk_string(card);
+
+        // this will operate on references to not move values out of m
+        match m.values().len() {
+            1 => {
+                // only one card in the map; five of a kind
+                HandType::FiveOfAKind
+            }
+            2 => {
+                // either a four of a kind or a full house, based on the values
+                let v = m.values().collect::<Vec<&usize>>();
+                let candidate = v.first().unwrap_or(&&0);
+                if candidate == &&4 || candidate == &&1 {
+                    // in a four of a kind, one card will have 4 appearances and one will have 1
+                    HandType::FourOfAKind
+                } else {
+                    HandType::FullHouse
+                }
+            }
+            3 => {
+                // either a three of a kind or a two pair; need to take a look of them all
+                let v = m.values().collect::<Vec<&usize>>();
+                let first = v.first().unwrap_or(&&0);
+                let second = v.get(1).unwrap_or(&&0);
+                let third = v.get(2).unwrap_or(&&0);
+
+                if first == &&2 || second == &&2 || third == &&2 {
+                    // if any of the cards have 2 matches, it's a two pair
+                    HandType::TwoPair
+                } else {
+                    HandType::ThreeOfAKind
+                }
+            }
+            4 => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    /// The best this type of hand can become once each 'J' in `cards` is a joker
+    pub fn with_joker(self, cards: &str) -> Self {
+        // count the jokers for decision making on upgrades
+        let chunks = chunk_string(cards);
+        let num_jokers = match chunks.get(&'J') {
+            None => {
+                return self;
+            }
+            Some(v) => *v,
+        };
+
+        // if there is a joker, try to upgrade the hand type
+        match self {
+            HandType::FiveOfAKind => {
+                // still have a five of a kind
+                HandType::FiveOfAKind
+            }
+            HandType::FourOfAKind => {
+                // can always upgrade to a five of a kind
+                HandType::FiveOfAKind
+            }
+            HandType::FullHouse => {
+                // either situations give a five of a kind (either have 3 jokers 2 others to
+                // upgrade to 5 or 2 jokers 3 others to upgrade to 5)
+                HandType::FiveOfAKind
+            }
+            HandType::ThreeOfAKind => {
+                // three of a kind means we can use the joker to upgrade to a foure of a kind (no
+                // matter how many jokers; either have 3 jokers that can turn to 3 of one of the
+                // remaining or 1 joker that can be either or)
+                HandType::FourOfAKind
+            }
+            HandType::TwoPair => {
+                if num_jokers == 2 {
+                    // 2 jokers can move to the other pair and become a four of a kind
+                    HandType::FourOfAKind
+                } else {
+                    // 1 joker can upgrade one of the two pairs to make the whole thing a fullhouse
+                    HandType::FullHouse
+                }
+            }
+            HandType::OnePair => {
+                // the joker may be the pair (so you can match another one of the leftovers for
+                // three) or the joker is alone and NOT the pair, so it can match the pair for a
+                // three of a kind
+                HandType::ThreeOfAKind
+            }
+            HandType::HighCard => {
+                // best you can do is turn the joker into a pair
+                HandType::OnePair
+            }
+        }
+    }
+
+    /// Gives a value that ranks the hands from lowest to highest
+    pub fn card_value(&self) -> i32 {
+        match self {
+            Self::FiveOfAKind => 6,
+            Self::FourOfAKind => 5,
+            Self::FullHouse => 4,
+            Self::ThreeOfAKind => 3,
+            Self::TwoPair => 2,
+            Self::OnePair => 1,
+            Self::HighCard => 0,
+        }
+    }
+}
+
+/// Card strength from weakest to strongest
+pub const CARD_ORDER: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+/// Card strength once 'J' is a joker (the weakest card)
+pub const JOKER_ORDER: [char; 13] = [
+    'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
+];
+
+/// Five cards and their bid
+#[derive(Debug, Clone, Eq)]
+pub struct Hand {
+    cards: String,
+    hand_type: HandType,
+    bid: i64,
+    /// 'J' is played as a joker
+    joker: bool,
+}
+
+impl Hand {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // a hand is exactly five known cards
+        let cards = verify(alphanumeric1, |c: &str| {
+            c.len() == 5 && c.chars().all(|c| CARD_ORDER.contains(&c))
+        });
+        let (remain, (cards, bid)) = separated_pair(cards, tag(" "), parse::number)(input)?;
+        Ok((
+            remain,
+            Self {
+                cards: cards.to_string(),
+                hand_type: HandType::new(cards),
+                bid,
+                joker: false,
+            },
+        ))
+    }
+
+    pub fn with_joker(&mut self) {
+        self.joker = true;
+        self.hand_type = self.hand_type.clone().with_joker(&self.cards);
+    }
+}
+
+impl Ord for Hand {
+    /// Compare two cards to one another. A card is "less" if it is "weaker" than other.
+    /// For example, if self is a hand with one pair and other is a hand with two pair, then
+    /// self will be Less than other. If self is a three of a kind and other is a two pair, then
+    /// self is Greater than other.
+    fn cmp(&self, other: &Self) -> Ordering {
+        // pick the order we care about
+        let char_order = if self.joker { JOKER_ORDER } else { CARD_ORDER };
+        self.cmp_by(other, &char_order)
+    }
+}
+
+impl Hand {
+    /// Compare like [`Ord`], ranking cards of the same hand type by `char_order` (weakest first)
+    pub fn cmp_by(&self, other: &Self, char_order: &[char]) -> Ordering {
+        // check if there are different HandTypes; if yes, go based on that
+        if self.hand_type.cmp(&other.hand_type).is_ne() {
+            return self.hand_type.cmp(&other.hand_type);
+        }
+
+        // otherwise, need to compare the cards one by one
+        let scards = self.cards.chars().collect::<Vec<char>>();
+        let ocards = other.cards.chars().collect::<Vec<char>>();
+
+        for (sc, oc) in scards.iter().zip(ocards.iter()) {
+            // same cards don't matter
+            if sc == oc {
+                continue;
+            }
+
+            // get the indices of the two cards, compare those
+            let scind = match char_order.iter().position(|&p| p == *sc) {
+                None => {
+                    return Ordering::Less;
+                }
+                Some(s) => s,
+            };
+            let ocind = match char_order.iter().position(|&p| p == *oc) {
+                None => {
+                    return Ordering::Greater;
+                }
+                Some(o) => o,
+            };
+            return scind.cmp(&ocind);
+        }
+        // based on the input, can't get here; but need it because rust
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.hand_type == other.hand_type && self.cards == other.cards
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CamelCards {
+    cards: Vec<Hand>,
+}
+
+impl CamelCards {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (remain, cards) = many0(preceded(multispace0, Hand::parse))(input)?;
+        // sort immediately
+        //cards.sort();
+        Ok((remain, Self { cards }))
+    }
+
+    pub fn total_winnings(&self) -> Result<i64, Error> {
+        let winnings = self
+            .cards
+            .iter()
+            .enumerate()
+            .map(|(rank, hand)| arith::mul(Day7::DAY, "winnings", (rank + 1) as i64, hand.bid))
+            .collect::<Result<Vec<_>, _>>()?;
+        arith::sum(Day7::DAY, "total winnings", winnings)
+    }
+
+    /// Total winnings with the cards ranked by `char_order` (weakest first)
+    pub fn without_joker(&mut self, char_order: &[char]) -> Result<i64, Error> {
+        self.cards.sort_by(|a, b| a.cmp_by(b, char_order));
+        self.total_winnings()
+    }
+
+    /// Total winnings once 'J' is a joker, with the cards ranked by `char_order` (weakest first)
+    pub fn with_joker(&mut self, char_order: &[char]) -> Result<i64, Error> {
+        // transform each card with a joker if applicable
+        for c in self.cards.iter_mut() {
+            c.with_joker();
+        }
+        // sort the cards based on updates
+        self.cards.sort_by(|a, b| a.cmp_by(b, char_order));
+        // compute new value
+        self.total_winnings()
+    }
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = CamelCards;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        // begin by parsing the cards and their bids
+        Ok(parse::all(Self::DAY, "hands", input, CamelCards::parse)?)
+    }
+
+    fn part1(&self, camel_cards: &Self::Input) -> Result<String> {
+        let mut camel_cards = camel_cards.clone();
+        let char_order: Vec<char> = self.card_order.chars().collect();
+        Ok(camel_cards.without_joker(&char_order)?.to_string())
+    }
+
+    fn part2(&self, camel_cards: &Self::Input) -> Result<String> {
+        let mut camel_cards = camel_cards.clone();
+        let char_order: Vec<char> = self.joker_order.chars().collect();
+        let p2 = camel_cards.with_joker(&char_order)?;
+        log::trace!("{:?}", camel_cards.cards);
+
+        Ok(p2.to_string())
+    }
+
+    /// `card_order = "23456789TJQKA"` and `joker_order = "J23456789TQKA"`, each every card once
+    fn configure(&mut self, params: Table) -> Result<()> {
+        let day: Self = config::params(Self::DAY, params)?;
+        for order in [&day.card_order, &day.joker_order] {
+            let mut cards: Vec<char> = order.chars().collect();
+            cards.sort_unstable();
+            let mut all = CARD_ORDER;
+            all.sort_unstable();
+            if cards != all {
+                bail!("'{order}' doesn't rank every card once");
+            }
+        }
+        *self = day;
+        Ok(())
+    }
+
+    fn commands(&self) -> Vec<Command<Self>> {
+        vec![
+            Command {
+                name: "hand",
+                args: "<cards>",
+                help: "the type of a hand",
+                run: |_, _, args| Ok(format!("{:?}", HandType::new(hand_cards(args[0])?))),
+            },
+            Command {
+                name: "joker",
+                args: "<cards>",
+                help: "the type of a hand once 'J' is a joker",
+                run: |_, _, args| {
+                    let cards = hand_cards(args[0])?;
+                    Ok(format!("{:?}", HandType::new(cards).with_joker(cards)))
+                },
+            },
+        ]
+    }
+}
+
+/// `cards` if they make a hand, for the commands
+fn hand_cards(cards: &str) -> Result<&str> {
+    if cards.len() != 5 || !cards.chars().all(|c| CARD_ORDER.contains(&c)) {
+        bail!(
+            "'{cards}' is not five cards from {}",
+            String::from_iter(CARD_ORDER)
+        );
+    }
+    Ok(cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{assert_examples, Part};
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day7::default(),
+            &[
+                (include_str!("../test.txt"), Part::One, "6440"),
+                (include_str!("../test.txt"), Part::Two, "5905"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_chunk_string() {
+        let input = "AATKQ";
+        let map = chunk_string(input);
+
+        assert_eq!(map[&'A'], 2);
+        assert_eq!(map[&'T'], 1);
+        assert_eq!(map[&'K'], 1);
+        assert_eq!(map[&'Q'], 1);
+    }
+
+    #[test]
+    fn test_hand_type() {
+        let five = "AAAAA";
+        let four = "AA8AA";
+        let full = "23332";
+        let three = "TTT98";
+        let two = "23432";
+        let one = "A23A4";
+        let high = "23456";
+
+        assert_eq!(HandType::new(five), HandType::FiveOfAKind);
+        assert_eq!(HandType::new(four), HandType::FourOfAKind);
+        assert_eq!(HandType::new(full), HandType::FullHouse);
+        assert_eq!(HandType::new(three), HandType::ThreeOfAKind);
+        assert_eq!(HandType::new(two), HandType::TwoPair);
+        assert_eq!(HandType::new(one), HandType::OnePair);
+        assert_eq!(HandType::new(high), HandType::HighCard);
+    }
+
+    #[test]
+    fn test_hand_type_with_joker() {
+        let joker = |cards| HandType::new(cards).with_joker(cards);
+
+        assert_eq!(joker("KTJJT"), HandType::FourOfAKind);
+        assert_eq!(joker("QQQJA"), HandType::FourOfAKind);
+        assert_eq!(joker("JJJJJ"), HandType::FiveOfAKind);
+        assert_eq!(joker("2345J"), HandType::OnePair);
+        assert_eq!(joker("KK677"), HandType::TwoPair);
+    }
+
+    #[test]
+    fn test_card_sorting() {
+        let input = include_str!("../test.txt");
+
+        let (_remaining, mut camel_cards) = CamelCards::parse(input).unwrap();
+
+        camel_cards.cards.sort();
+
+        // first card should be a one pair ("32T3K")
+        assert_eq!(
+            camel_cards.cards.first().unwrap().hand_type,
+            HandType::OnePair
+        );
+        assert_eq!(camel_cards.cards.first().unwrap().cards, "32T3K");
+        // second card should be a two pair ("KTJJT")
+        assert_eq!(
+            camel_cards.cards.get(1).unwrap().hand_type,
+            HandType::TwoPair
+        );
+        assert_eq!(camel_cards.cards.get(1).unwrap().cards, "KTJJT");
+        // third card should be a two pair ("KK677")
+        assert_eq!(
+            camel_cards.cards.get(2).unwrap().hand_type,
+            HandType::TwoPair
+        );
+        assert_eq!(camel_cards.cards.get(2).unwrap().cards, "KK677");
+        // fourth card should be a three of a kind ("T55J5")
+        assert_eq!(
+            camel_cards.cards.get(3).unwrap().hand_type,
+            HandType::ThreeOfAKind
+        );
+        assert_eq!(camel_cards.cards.get(3).unwrap().cards, "T55J5");
+        // third card should be a two pair ("QQQJA")
+        assert_eq!(
+            camel_cards.cards.get(4).unwrap().hand_type,
+            HandType::ThreeOfAKind
+        );
+        assert_eq!(camel_cards.cards.get(4).unwrap().cards, "QQQJA");
+    }
+}
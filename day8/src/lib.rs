@@ -0,0 +1,345 @@
+use anyhow::{bail, Result};
+use common::config::{self, Table};
+use common::parse::{self, key_value, lines};
+use common::repl::Command;
+use common::{Error, Solution};
+use num::{BigUint, Integer, One};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, char, line_ending, multispace0},
+    combinator::value,
+    multi::many1,
+    sequence::delimited,
+    sequence::pair,
+    sequence::preceded,
+    sequence::separated_pair,
+    IResult,
+};
+
+/// Day 8: Haunted Wasteland
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day8 {
+    /// Last letter of the locations the ghosts start from in part 2
+    pub start: char,
+    /// Last letter of the locations the ghosts are headed for in part 2
+    pub end: char,
+}
+
+impl Default for Day8 {
+    fn default() -> Self {
+        Self {
+            start: 'A',
+            end: 'Z',
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Step {
+    Left,
+    Right,
+}
+
+impl Step {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // either a right or left
+        alt((value(Step::Right, char('R')), value(Step::Left, char('L'))))(input)
+    }
+}
+
+/// Helper type to assist in walking through the map
+/// Impl's into iterator to create a wrap around ring buffer through
+/// the Map's steps
+pub struct Navigator<'a> {
+    map: &'a Map,
+    curr: usize,
+}
+
+impl<'a> Iterator for Navigator<'a> {
+    type Item = &'a Step;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // return the self.curr % self.map.steps.len() step
+        let ret = self
+            .map
+            .steps
+            .get(self.curr.checked_rem(self.map.steps.len())?);
+        self.curr += 1;
+        ret
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    /// Holds the steps to take through the map
+    steps: Vec<Step>,
+
+    /// Holds the actual mappings to walk through
+    mapping: HashMap<String, (String, String)>,
+}
+
+impl Map {
+    pub fn iter(&self) -> Navigator<'_> {
+        Navigator { map: self, curr: 0 }
+    }
+
+    /// Most steps a walk can take before it must be going round in circles: by then some
+    /// (location, step) pair has repeated
+    pub fn max_walk(&self) -> usize {
+        self.mapping.len().max(1) * self.steps.len()
+    }
+
+    /// Where taking `step` from `loc` leads, failing if `loc` isn't in the map
+    pub fn get_next_loc(&self, loc: &str, step: &Step) -> Result<String, Error> {
+        let (left, right) = self
+            .mapping
+            .get(loc)
+            .ok_or_else(|| Error::invalid(Day8::DAY, format!("no {loc} in the map")))?;
+        Ok(match step {
+            Step::Left => left.clone(),
+            Step::Right => right.clone(),
+        })
+    }
+
+    /// gather the starting positions, the ones ending in `start` (for part 2)
+    pub fn starting_positions(&self, start: char) -> Vec<String> {
+        let mut starting = Vec::new();
+        // loop through all of the keys in the mapping
+        for loc in self.mapping.keys() {
+            if loc.ends_with(start) {
+                starting.push(loc.clone());
+            }
+        }
+        starting
+    }
+}
+
+/// The instructions, all on the first line
+fn step_parser(input: &str) -> IResult<&str, Vec<Step>> {
+    many1(Step::parse)(input)
+}
+
+fn parse_dest(input: &str) -> IResult<&str, (&str, &str)> {
+    separated_pair(
+        alphanumeric1,
+        tag(","),
+        preceded(multispace0, alphanumeric1),
+    )(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    key_value(
+        alphanumeric1,
+        "=",
+        delimited(tag("("), parse_dest, tag(")")),
+    )(input)
+}
+
+fn map_parser(input: &str) -> IResult<&str, HashMap<String, (String, String)>> {
+    let (remain, maps) = lines(parse_line)(input)?;
+    let mut mapping = HashMap::new();
+    // loop through each of the destinations we get and add each to a hashmap
+    for m in &maps {
+        match mapping.insert(m.0.to_string(), (m.1 .0.to_string(), m.1 .1.to_string())) {
+            None => {
+                continue;
+            }
+            Some(_) => {
+                continue;
+            }
+        }
+    }
+    Ok((remain, mapping))
+}
+
+pub fn parse(input: &str) -> Result<Map> {
+    let (steps, map) = parse::all(
+        Day8::DAY,
+        "map",
+        input,
+        separated_pair(step_parser, pair(line_ending, line_ending), map_parser),
+    )?;
+
+    Ok(Map {
+        steps,
+        mapping: map,
+    })
+}
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let map = parse(input)?;
+        log::trace!("{map:?}");
+        Ok(map)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<String> {
+        // loop through the map and count each step
+        let mut loc = "AAA".to_string();
+        let mut steps = 0;
+        if !map.mapping.contains_key(&loc) {
+            return Err(Error::invalid(Self::DAY, "no AAA in the map").into());
+        }
+
+        for step in map.iter() {
+            if steps > map.max_walk() {
+                return Err(Error::invalid(Self::DAY, "AAA never reaches ZZZ").into());
+            }
+            loc = map.get_next_loc(&loc, step)?;
+            log::trace!("Loc: {loc}, Step: {step:?}");
+            steps += 1;
+            if loc == "ZZZ" {
+                break;
+            }
+        }
+
+        Ok(steps.to_string())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<String> {
+        // could have included some state into the iterator, but it doesn't
+        // necessarily make the most sense. the iterator truly should just move
+        // one step along the path and expose the next one. we should keep state internally
+
+        // gather the starting positions
+        let mut positions = map.starting_positions(self.start);
+        let mut steps: u64 = 0;
+        let mut distances = Vec::new();
+        if positions.is_empty() {
+            return Err(Error::invalid(
+                Self::DAY,
+                format!("no locations ending in '{}'", self.start),
+            )
+            .into());
+        }
+
+        // have to be smart :) need to figure out the length of the route from
+        // each of the starting positions to their end (when each of them hit a 'Z')
+        // we can remove the ones that have a hit their point from positions
+        // once we know how many steps it takes to get to each of these distances,
+        // we can then find how long it would take to get to each of them.
+        // if it takes 3 steps to complete route A and 4 steps to complete route B, then it would take
+        // 12 steps to finish both of them simultaneously (complete route A 4 times, route B 3 times
+        //    and both will be at the end)
+        // thus, we need to find the LCM for all of our starting positions
+
+        for step in map.iter() {
+            if steps > map.max_walk() as u64 {
+                return Err(Error::invalid(
+                    Self::DAY,
+                    format!(
+                        "a location ending in '{}' never reaches one ending in '{}'",
+                        self.start, self.end
+                    ),
+                )
+                .into());
+            }
+            // grab the next location for each of the positions
+            let mut next_pos = Vec::new();
+            for p in &positions {
+                next_pos.push(map.get_next_loc(p, step)?);
+            }
+
+            steps += 1;
+
+            // keep only the positions that don't end in the destination letter
+            next_pos.retain(|x| !x.ends_with(self.end));
+            // add the difference in the lengths of the positions and the next_pos
+            // that we kept
+            // (this ensure that if two routes end up both ending, we still retain it)
+            for _ in 0..(positions.len() - next_pos.len()) {
+                distances.push(steps);
+            }
+
+            positions = next_pos;
+            // if we don't have anything
+            if positions.is_empty() {
+                break;
+            }
+        }
+        log::debug!("Got distances: {distances:?}");
+
+        // now we need to find the LCM of all of these numbers together. it grows with every ghost
+        // whose distance shares few factors with the others, so it isn't bounded by any fixed
+        // width integer
+        let p2 = distances
+            .iter()
+            .fold(BigUint::one(), |acc, x| acc.lcm(&BigUint::from(*x)));
+
+        Ok(p2.to_string())
+    }
+
+    /// `start = "A"` and `end = "Z"`
+    fn configure(&mut self, params: Table) -> Result<()> {
+        *self = config::params(Self::DAY, params)?;
+        Ok(())
+    }
+
+    fn commands(&self) -> Vec<Command<Self>> {
+        vec![Command {
+            name: "next",
+            args: "<loc> <L|R>",
+            help: "the location a step from a location leads to",
+            run: |_, map, args| {
+                let step = match Step::parse(args[1]) {
+                    Ok(("", step)) => step,
+                    _ => bail!("invalid step '{}': expected L or R", args[1]),
+                };
+                Ok(map.get_next_loc(args[0], &step)?)
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{assert_examples, Part};
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day8::default(),
+            &[
+                (include_str!("../test1.txt"), Part::One, "2"),
+                (include_str!("../test2.txt"), Part::One, "6"),
+                (include_str!("../test3.txt"), Part::Two, "6"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_undefined_location() {
+        let map = parse("LR\n\nAAA = (BBB, CCC)\n").unwrap();
+        assert_eq!(map.get_next_loc("AAA", &Step::Right).unwrap(), "CCC");
+        let e = map.get_next_loc("BBB", &Step::Left).unwrap_err();
+        assert!(e.to_string().contains("no BBB in the map"), "{e}");
+    }
+
+    #[test]
+    fn test_nodes_starting_with_steps() {
+        let map = parse("RL\n\nRRB = (LLA, RRB)\nLLA = (RRB, LLA)\n").unwrap();
+        assert_eq!(map.steps.len(), 2);
+        assert_eq!(map.mapping["RRB"], ("LLA".to_string(), "RRB".to_string()));
+        assert!(map.mapping.contains_key("LLA"));
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let line = "AAA = (AAA, BBB)";
+        let (_, p) = parse_line(line).unwrap();
+
+        assert_eq!(p.0, "AAA");
+        assert_eq!(p.1 .0, "AAA");
+        assert_eq!(p.1 .1, "BBB");
+    }
+}
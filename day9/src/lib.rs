@@ -0,0 +1,103 @@
+use anyhow::Result;
+use common::parse::{self, lines, numbers};
+use common::{arith, Error, Solution};
+use rayon::prelude::*;
+
+/// Day 9: Mirage Maintenance
+pub struct Day9;
+
+/// Computes the differences between each value. Returns None once they're all zeros
+pub fn differences(l: &[i64]) -> Result<Option<Vec<i64>>, Error> {
+    let mut diffs = Vec::new();
+    // be efficient to see if we have all zeros
+    let mut zeros = true;
+    for i in 1..l.len() {
+        let d = arith::sub(Day9::DAY, "difference", l[i], l[i - 1])?;
+        if d != 0 {
+            zeros = false;
+        }
+        diffs.push(d);
+    }
+
+    if zeros {
+        Ok(None)
+    } else {
+        Ok(Some(diffs))
+    }
+}
+
+/// Extrapolates the value after the end of the history
+pub fn next_value(l: &[i64]) -> Result<i64, Error> {
+    let Some(&last_value) = l.last() else {
+        return Ok(0);
+    };
+
+    // if we have all zeros, it's time to move up (just return last value because it'll just add to
+    // 0)
+    match differences(l)? {
+        None => Ok(last_value),
+        Some(diffs) => arith::add(Day9::DAY, "next value", last_value, next_value(&diffs)?),
+    }
+}
+
+/// Extrapolates the value before the start of the history
+pub fn previous_value(l: &[i64]) -> Result<i64, Error> {
+    let Some(&first_value) = l.first() else {
+        return Ok(0);
+    };
+
+    // if all zeros then we need to go back up
+    match differences(l)? {
+        None => Ok(first_value),
+        Some(diffs) => arith::sub(
+            Day9::DAY,
+            "previous value",
+            first_value,
+            previous_value(&diffs)?,
+        ),
+    }
+}
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse::all(Self::DAY, "histories", input, lines(numbers))?)
+    }
+
+    fn part1(&self, histories: &Self::Input) -> Result<String> {
+        // every history extrapolates independently
+        let values = histories
+            .par_iter()
+            .map(|l| next_value(l))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(arith::sum(Self::DAY, "sum of next values", values)?.to_string())
+    }
+
+    fn part2(&self, histories: &Self::Input) -> Result<String> {
+        let values = histories
+            .par_iter()
+            .map(|l| previous_value(l))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(arith::sum(Self::DAY, "sum of previous values", values)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{assert_examples, Part};
+
+    #[test]
+    fn test_examples() {
+        assert_examples(
+            &Day9,
+            &[
+                (include_str!("../test.txt"), Part::One, "114"),
+                (include_str!("../test.txt"), Part::Two, "2"),
+            ],
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    common::cli::run_main(&mut day9::Day9)
+}
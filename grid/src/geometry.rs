@@ -0,0 +1,210 @@
+//! Positions and directions on a grid. Rows grow downwards, so north is row - 1.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A (row, col) position, or the offset between two of them. Signed so that stepping off any edge
+/// is representable; convert back with [`Coordinate::to_usize`] before indexing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coordinate {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Coordinate {
+    pub const fn new(row: i64, col: i64) -> Self {
+        Self { row, col }
+    }
+
+    pub fn from_usize(row: usize, col: usize) -> Self {
+        Self::new(row as i64, col as i64)
+    }
+
+    /// (row, col) as indices, or None if either is negative
+    pub fn to_usize(self) -> Option<(usize, usize)> {
+        Some((
+            usize::try_from(self.row).ok()?,
+            usize::try_from(self.col).ok()?,
+        ))
+    }
+
+    /// The position one step away in `dir`
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.offset()
+    }
+
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// The four orthogonally adjacent positions
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction::ORTHOGONAL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// All eight surrounding positions
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+}
+
+impl From<(usize, usize)> for Coordinate {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self::from_usize(row, col)
+    }
+}
+
+impl From<Direction> for Coordinate {
+    fn from(dir: Direction) -> Self {
+        dir.offset()
+    }
+}
+
+impl Add for Coordinate {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign for Coordinate {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Coordinate {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign for Coordinate {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Coordinate {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        Self::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+impl Neg for Coordinate {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.row, -self.col)
+    }
+}
+
+/// One of the eight compass directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// North, east, south and west
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Every direction, clockwise from the top left
+    pub const ALL: [Direction; 8] = [
+        Direction::NorthWest,
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+    ];
+
+    /// Change in (row, col) from moving one step this way
+    pub const fn offset(self) -> Coordinate {
+        match self {
+            Direction::North => Coordinate::new(-1, 0),
+            Direction::NorthEast => Coordinate::new(-1, 1),
+            Direction::East => Coordinate::new(0, 1),
+            Direction::SouthEast => Coordinate::new(1, 1),
+            Direction::South => Coordinate::new(1, 0),
+            Direction::SouthWest => Coordinate::new(1, -1),
+            Direction::West => Coordinate::new(0, -1),
+            Direction::NorthWest => Coordinate::new(-1, -1),
+        }
+    }
+
+    pub const fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::NorthEast => Direction::SouthWest,
+            Direction::East => Direction::West,
+            Direction::SouthEast => Direction::NorthWest,
+            Direction::South => Direction::North,
+            Direction::SouthWest => Direction::NorthEast,
+            Direction::West => Direction::East,
+            Direction::NorthWest => Direction::SouthEast,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Coordinate::new(2, 3);
+        let b = Coordinate::new(-1, 5);
+        assert_eq!(a + b, Coordinate::new(1, 8));
+        assert_eq!(a - b, Coordinate::new(3, -2));
+        assert_eq!(-a, Coordinate::new(-2, -3));
+        assert_eq!(a * 3, Coordinate::new(6, 9));
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(b.manhattan(a), 5);
+    }
+
+    #[test]
+    fn test_usize_conversions() {
+        assert_eq!(Coordinate::from((4, 7)).to_usize(), Some((4, 7)));
+        assert_eq!(
+            Coordinate::new(0, 0).step(Direction::North).to_usize(),
+            None
+        );
+        assert_eq!(
+            Coordinate::new(0, 0).step(Direction::SouthEast).to_usize(),
+            Some((1, 1))
+        );
+    }
+
+    #[test]
+    fn test_directions() {
+        for dir in Direction::ALL {
+            assert_eq!(
+                dir.offset() + dir.opposite().offset(),
+                Coordinate::default()
+            );
+        }
+        assert_eq!(Coordinate::default().neighbors8().count(), 8);
+        assert!(Coordinate::default()
+            .neighbors4()
+            .all(|c| c.manhattan(Coordinate::default()) == 1));
+    }
+}
@@ -0,0 +1,314 @@
+//! Two dimensional grid shared by the days whose input is a map of characters
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+pub mod geometry;
+
+pub use geometry::{Coordinate, Direction};
+
+/// Errors building a grid from text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// A row didn't have as many columns as the first row
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A cell couldn't be converted
+    InvalidCell { row: usize, col: usize, value: char },
+}
+
+impl std::error::Error for GridError {}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(f, "row {row} has {found} columns, expected {expected}"),
+            Self::InvalidCell { row, col, value } => {
+                write!(f, "invalid cell '{value}' at row {row}, column {col}")
+            }
+        }
+    }
+}
+
+/// Rectangular grid stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Parse a block of text with one row per line
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        Self::parse_with(input, Some)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse a block of text with one row per line, converting each character with `cell`.
+    /// `cell` returning None is reported as an invalid cell.
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridError> {
+        let mut rows = Vec::new();
+        for (row, line) in input.lines().enumerate() {
+            let mut r = Vec::with_capacity(line.len());
+            for (col, value) in line.chars().enumerate() {
+                r.push(cell(value).ok_or(GridError::InvalidCell { row, col, value })?);
+            }
+            rows.push(r);
+        }
+        Self::from_rows(rows)
+    }
+
+    /// Build a grid from its rows, which all need the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let cols = rows.first().map_or(0, Vec::len);
+        let num_rows = rows.len();
+        let mut cells = Vec::with_capacity(num_rows * cols);
+        for (row, r) in rows.into_iter().enumerate() {
+            if r.len() != cols {
+                return Err(GridError::Ragged {
+                    row,
+                    expected: cols,
+                    found: r.len(),
+                });
+            }
+            cells.extend(r);
+        }
+        Ok(Self {
+            rows: num_rows,
+            cols,
+            cells,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns true if (row, col) is inside the grid
+    pub fn contains(&self, row: i64, col: i64) -> bool {
+        row >= 0 && col >= 0 && (row as usize) < self.rows && (col as usize) < self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.cells.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            self.cells.get_mut(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    /// Checked lookup for signed coordinates that may have walked off any edge
+    pub fn get_signed(&self, row: i64, col: i64) -> Option<&T> {
+        self.at(Coordinate::new(row, col))
+    }
+
+    /// Checked lookup by [`Coordinate`]
+    pub fn at(&self, pos: Coordinate) -> Option<&T> {
+        let (row, col) = pos.to_usize()?;
+        self.get(row, col)
+    }
+
+    pub fn at_mut(&mut self, pos: Coordinate) -> Option<&mut T> {
+        let (row, col) = pos.to_usize()?;
+        self.get_mut(row, col)
+    }
+
+    fn inside<'a>(
+        &'a self,
+        positions: impl Iterator<Item = Coordinate> + 'a,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        positions.filter_map(|pos| {
+            let (row, col) = pos.to_usize()?;
+            (row < self.rows && col < self.cols).then_some((row, col))
+        })
+    }
+
+    /// Positions of the up, right, down and left neighbours that are inside the grid
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.inside(Coordinate::from_usize(row, col).neighbors4())
+    }
+
+    /// Positions of the eight surrounding cells that are inside the grid
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.inside(Coordinate::from_usize(row, col).neighbors8())
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row < self.rows {
+            Some(&self.cells[row * self.cols..(row + 1) * self.cols])
+        } else {
+            None
+        }
+    }
+
+    /// Every cell of one column, top to bottom
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        let cells = if col < self.cols {
+            &self.cells[col..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.cols.max(1))
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    /// Every cell along with its (row, col), row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, v)| ((i / self.cols, i % self.cols), v))
+    }
+
+    /// Build a new grid of the same shape by converting every cell
+    pub fn map<U>(&self, mut f: impl FnMut((usize, usize), &T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.iter().map(|(pos, v)| f(pos, v)).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.cols, "column {col} out of bounds");
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(col < self.cols, "column {col} out of bounds");
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for val in row {
+                write!(f, "{val}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc\ndef\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!(grid.rows(), 2);
+        assert_eq!(grid.cols(), 3);
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 1), Some(&'e'));
+        assert_eq!(grid.at(Coordinate::new(0, -1)), None);
+        assert_eq!(grid.at(Coordinate::new(0, 2)), Some(&'c'));
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        assert_eq!(
+            Grid::parse("abc\nde"),
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let grid = Grid::parse_with("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(grid[(1, 0)], 3);
+        assert_eq!(
+            Grid::parse_with("1x", |c| c.to_digit(10)),
+            Err(GridError::InvalidCell {
+                row: 0,
+                col: 1,
+                value: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8(0, 1).count(), 5);
+        assert_eq!(grid.neighbors8(1, 1).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.iter_columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!(grid.to_string(), INPUT);
+    }
+}